@@ -0,0 +1,582 @@
+// Ecore_Con Rust bindings for EFL.
+// Copyright (C) 2014  Luis Araujo <araujoc.luisf@gmail.com>
+
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 2.1 of the License, or (at your option) any later version.
+
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+
+/*
+ * TCP and local (UNIX socket) servers and clients, and HTTP
+ * transfers, running on the ecore main loop.
+ */
+
+extern crate libc;
+
+use std::mem::transmute;
+use std::ptr;
+use std::vec::raw;
+
+use ecore::con::libc::{c_int, c_uint, c_long, c_char, c_void};
+use ecore;
+use eina;
+use eseful::{from_eina_to_bool, from_c_to_string, EventInfo};
+
+
+pub enum EcoreConServer {}
+pub enum EcoreConClient {}
+pub enum EcoreConUrl {}
+
+/// Types for an ecore_con server or client connection.
+/// The connection type can be or'ed with the ECORE_CON_USE_* flags.
+pub type ConType = c_uint;
+
+/// Socket in ~/.ecore
+pub static ECORE_CON_LOCAL_USER: ConType = 0;
+/// Socket in /tmp
+pub static ECORE_CON_LOCAL_SYSTEM: ConType = 1;
+/// Abstract socket
+pub static ECORE_CON_LOCAL_ABSTRACT: ConType = 2;
+/// Remote server using TCP
+pub static ECORE_CON_REMOTE_TCP: ConType = 3;
+/// Remote multicast server
+pub static ECORE_CON_REMOTE_MCAST: ConType = 4;
+/// Remote server using UDP
+pub static ECORE_CON_REMOTE_UDP: ConType = 5;
+/// Remote broadcast using UDP
+pub static ECORE_CON_REMOTE_BROADCAST: ConType = 6;
+/// Remote connection sending packets immediately
+pub static ECORE_CON_REMOTE_NODELAY: ConType = 7;
+/// Remote connection sending data in large chunks
+pub static ECORE_CON_REMOTE_CORK: ConType = 8;
+/// Use TLS
+pub static ECORE_CON_USE_TLS: ConType = 64;
+/// Attempt to use the loaded certificate
+pub static ECORE_CON_LOAD_CERT: ConType = 128;
+/// Disable all types of proxy on the server
+pub static ECORE_CON_NO_PROXY: ConType = 256;
+
+/// Handle of a server, either listening (from 'server_add') or
+/// connected to (from 'server_connect').
+pub struct Server {
+    _eo: *EcoreConServer
+}
+
+/// Handle of a client connected to a listening server.
+pub struct Client {
+    _eo: *EcoreConClient
+}
+
+/// Owned handle of an URL transfer.
+/// Dropping the handle aborts the transfer and frees it.
+pub struct Url {
+    _eo: *EcoreConUrl
+}
+
+/// A chunk of data received by an URL transfer.
+pub struct UrlEventData {
+    pub url_con: *EcoreConUrl,
+    pub data: Vec<u8>
+}
+
+/// Progress of an URL transfer, in bytes.
+pub struct UrlEventProgress {
+    pub url_con: *EcoreConUrl,
+    pub down_total: f64,
+    pub down_now: f64,
+    pub up_total: f64,
+    pub up_now: f64
+}
+
+/// An URL transfer completed.
+pub struct UrlEventComplete {
+    pub url_con: *EcoreConUrl,
+    /// The HTTP status code of the response.
+    pub status: int
+}
+
+pub type ConClientClosure = Box<FnMut(&Client) -> bool + 'static>;
+pub type ConClientDataClosure = Box<FnMut(&Client, &[u8]) -> bool + 'static>;
+pub type ConClientErrorClosure = Box<FnMut(&Client, &str) -> bool + 'static>;
+pub type ConServerClosure = Box<FnMut(&Server) -> bool + 'static>;
+pub type ConServerDataClosure = Box<FnMut(&Server, &[u8]) -> bool + 'static>;
+pub type ConServerErrorClosure = Box<FnMut(&Server, &str) -> bool + 'static>;
+pub type ConUrlDataClosure = Box<FnMut(&UrlEventData) -> bool + 'static>;
+pub type ConUrlProgressClosure = Box<FnMut(&UrlEventProgress) -> bool + 'static>;
+pub type ConUrlCompleteClosure = Box<FnMut(&UrlEventComplete) -> bool + 'static>;
+
+/* C representation of the connection events.
+ * The add and del events only hold the client or server. */
+struct _CConEventClientData {
+    client: *EcoreConClient,
+    data: *c_void,
+    size: c_int
+}
+
+struct _CConEventClientError {
+    client: *EcoreConClient,
+    error: *c_char
+}
+
+struct _CConEventServerData {
+    server: *EcoreConServer,
+    data: *c_void,
+    size: c_int
+}
+
+struct _CConEventServerError {
+    server: *EcoreConServer,
+    error: *c_char
+}
+
+struct _CConEventUrlData {
+    url_con: *EcoreConUrl,
+    size: c_int,
+    /* The data is stored inline, following the size */
+    data: [u8, ..1]
+}
+
+struct _CConEventUrlProgress {
+    url_con: *EcoreConUrl,
+    down_total: f64,
+    down_now: f64,
+    up_total: f64,
+    up_now: f64
+}
+
+struct _CConEventUrlComplete {
+    url_con: *EcoreConUrl,
+    status: c_int
+}
+
+
+#[link(name = "ecore_con")]
+extern "C" {
+    static ECORE_CON_EVENT_CLIENT_ADD: c_int;
+    static ECORE_CON_EVENT_CLIENT_DEL: c_int;
+    static ECORE_CON_EVENT_CLIENT_ERROR: c_int;
+    static ECORE_CON_EVENT_CLIENT_DATA: c_int;
+    static ECORE_CON_EVENT_SERVER_ADD: c_int;
+    static ECORE_CON_EVENT_SERVER_DEL: c_int;
+    static ECORE_CON_EVENT_SERVER_ERROR: c_int;
+    static ECORE_CON_EVENT_SERVER_DATA: c_int;
+    fn ecore_con_init() -> c_int;
+    fn ecore_con_shutdown() -> c_int;
+    fn ecore_con_server_add(ctype: c_uint, name: *c_char, port: c_int,
+                            data: *c_void) -> *EcoreConServer;
+    fn ecore_con_server_connect(ctype: c_uint, name: *c_char, port: c_int,
+                                data: *c_void) -> *EcoreConServer;
+    fn ecore_con_server_del(svr: *EcoreConServer) -> *c_void;
+    fn ecore_con_server_send(svr: *EcoreConServer, data: *c_void, size: c_int) -> c_int;
+    fn ecore_con_server_flush(svr: *EcoreConServer);
+    fn ecore_con_server_connected_get(svr: *EcoreConServer) -> eina::EinaBool;
+    fn ecore_con_server_name_get(svr: *EcoreConServer) -> *c_char;
+    fn ecore_con_server_port_get(svr: *EcoreConServer) -> c_int;
+    fn ecore_con_server_ip_get(svr: *EcoreConServer) -> *c_char;
+    fn ecore_con_server_client_limit_set(svr: *EcoreConServer, client_limit: c_int,
+                                         reject_excess_clients: c_char);
+    fn ecore_con_client_send(cl: *EcoreConClient, data: *c_void, size: c_int) -> c_int;
+    fn ecore_con_client_del(cl: *EcoreConClient) -> *c_void;
+    fn ecore_con_client_flush(cl: *EcoreConClient);
+    fn ecore_con_client_server_get(cl: *EcoreConClient) -> *EcoreConServer;
+    fn ecore_con_client_connected_get(cl: *EcoreConClient) -> eina::EinaBool;
+    fn ecore_con_client_ip_get(cl: *EcoreConClient) -> *c_char;
+    fn ecore_con_client_port_get(cl: *EcoreConClient) -> c_int;
+    static ECORE_CON_EVENT_URL_DATA: c_int;
+    static ECORE_CON_EVENT_URL_COMPLETE: c_int;
+    static ECORE_CON_EVENT_URL_PROGRESS: c_int;
+    fn ecore_con_url_init() -> c_int;
+    fn ecore_con_url_shutdown() -> c_int;
+    fn ecore_con_url_new(url: *c_char) -> *EcoreConUrl;
+    fn ecore_con_url_free(url_con: *EcoreConUrl);
+    fn ecore_con_url_url_get(url_con: *EcoreConUrl) -> *c_char;
+    fn ecore_con_url_get(url_con: *EcoreConUrl) -> eina::EinaBool;
+    fn ecore_con_url_post(url_con: *EcoreConUrl, data: *c_void, length: c_long,
+                          content_type: *c_char) -> eina::EinaBool;
+    fn ecore_con_url_additional_header_add(url_con: *EcoreConUrl,
+                                           key: *c_char, value: *c_char);
+    fn ecore_con_url_additional_headers_clear(url_con: *EcoreConUrl);
+    fn ecore_con_url_fd_set(url_con: *EcoreConUrl, fd: c_int);
+    fn ecore_con_url_timeout_set(url_con: *EcoreConUrl, timeout: f64);
+    fn ecore_con_url_received_bytes_get(url_con: *EcoreConUrl) -> c_int;
+    fn ecore_con_url_status_code_get(url_con: *EcoreConUrl) -> c_int;
+}
+
+
+impl Server {
+    /// Whether the given handle refers to the same server.
+    pub fn is(&self, other: &Server) -> bool {
+        self._eo == other._eo
+    }
+
+    /// Whether the server could be created or connected to.
+    pub fn is_valid(&self) -> bool {
+        self._eo.is_not_null()
+    }
+
+    /// Close the connection and free the server.
+    pub fn del(self) {
+        unsafe { ecore_con_server_del(self._eo); }
+    }
+
+    /// Send the given data to the server.
+    /// Returns the number of bytes queued for sending.
+    pub fn send(&self, data: &[u8]) -> int {
+        unsafe {
+            ecore_con_server_send(self._eo, transmute(data.as_ptr()),
+                                  data.len() as c_int) as int
+        }
+    }
+
+    /// Flush all pending data to the server.
+    pub fn flush(&self) {
+        unsafe { ecore_con_server_flush(self._eo) }
+    }
+
+    /// Whether the connection to the server is established.
+    pub fn connected_get(&self) -> bool {
+        unsafe { from_eina_to_bool(ecore_con_server_connected_get(self._eo)) }
+    }
+
+    /// Retrieve the name of the server.
+    pub fn name_get(&self) -> String {
+        unsafe {
+            from_c_to_string(ecore_con_server_name_get(self._eo)).unwrap_or(String::new())
+        }
+    }
+
+    /// Retrieve the port of the server.
+    pub fn port_get(&self) -> int {
+        unsafe { ecore_con_server_port_get(self._eo) as int }
+    }
+
+    /// Retrieve the IP address of a connected server.
+    pub fn ip_get(&self) -> String {
+        unsafe {
+            from_c_to_string(ecore_con_server_ip_get(self._eo)).unwrap_or(String::new())
+        }
+    }
+
+    /// Set a limit on the number of clients that can connect at once.
+    /// A negative 'client_limit' means no limit.
+    pub fn client_limit_set(&self, client_limit: int, reject_excess_clients: bool) {
+        unsafe {
+            ecore_con_server_client_limit_set(self._eo, client_limit as c_int,
+                                              reject_excess_clients as c_char)
+        }
+    }
+}
+
+impl Client {
+    /// Whether the given handle refers to the same client.
+    pub fn is(&self, other: &Client) -> bool {
+        self._eo == other._eo
+    }
+
+    /// Close the connection and free the client.
+    pub fn del(self) {
+        unsafe { ecore_con_client_del(self._eo); }
+    }
+
+    /// Send the given data to the client.
+    /// Returns the number of bytes queued for sending.
+    pub fn send(&self, data: &[u8]) -> int {
+        unsafe {
+            ecore_con_client_send(self._eo, transmute(data.as_ptr()),
+                                  data.len() as c_int) as int
+        }
+    }
+
+    /// Flush all pending data to the client.
+    pub fn flush(&self) {
+        unsafe { ecore_con_client_flush(self._eo) }
+    }
+
+    /// Get the server the client is connected to.
+    pub fn server_get(&self) -> Server {
+        Server { _eo: unsafe { ecore_con_client_server_get(self._eo) } }
+    }
+
+    /// Whether the client is still connected.
+    pub fn connected_get(&self) -> bool {
+        unsafe { from_eina_to_bool(ecore_con_client_connected_get(self._eo)) }
+    }
+
+    /// Retrieve the IP address of the client.
+    pub fn ip_get(&self) -> String {
+        unsafe {
+            from_c_to_string(ecore_con_client_ip_get(self._eo)).unwrap_or(String::new())
+        }
+    }
+
+    /// Retrieve the port the client is connected from.
+    pub fn port_get(&self) -> int {
+        unsafe { ecore_con_client_port_get(self._eo) as int }
+    }
+}
+
+impl Url {
+    /// Whether the given raw Ecore_Con_Url, as found in the event
+    /// payloads, refers to this transfer.
+    pub fn is(&self, url_con: *EcoreConUrl) -> bool {
+        self._eo == url_con
+    }
+
+    /// Whether the transfer could be created.
+    pub fn is_valid(&self) -> bool {
+        self._eo.is_not_null()
+    }
+
+    /// Get the URL of the transfer.
+    pub fn url_get(&self) -> String {
+        unsafe {
+            from_c_to_string(ecore_con_url_url_get(self._eo)).unwrap_or(String::new())
+        }
+    }
+
+    /// Send a GET request.
+    /// The response is delivered through the URL events.
+    pub fn get(&self) -> bool {
+        unsafe { from_eina_to_bool(ecore_con_url_get(self._eo)) }
+    }
+
+    /// Send a POST request with 'data' of the given 'content_type'.
+    pub fn post(&self, data: &[u8], content_type: Option<&str>) -> bool {
+        unsafe {
+            let c_data: *c_void = transmute(data.as_ptr());
+            let length = data.len() as c_long;
+            from_eina_to_bool(match content_type {
+                None => ecore_con_url_post(self._eo, c_data, length, ptr::null()),
+                Some(ct) => ct.with_c_str(|c_ct| {
+                    ecore_con_url_post(self._eo, c_data, length, c_ct)
+                })
+            })
+        }
+    }
+
+    /// Add an additional header to the request.
+    pub fn additional_header_add(&self, key: &str, value: &str) {
+        key.with_c_str(|c_key| unsafe {
+            value.with_c_str(|c_value| {
+                ecore_con_url_additional_header_add(self._eo, c_key, c_value)
+            })
+        })
+    }
+
+    /// Clean additional headers.
+    pub fn additional_headers_clear(&self) {
+        unsafe { ecore_con_url_additional_headers_clear(self._eo) }
+    }
+
+    /// Write the received data to the file descriptor 'fd' instead of
+    /// sending URL data events.
+    pub fn fd_set(&self, fd: ecore::RawFd) {
+        unsafe { ecore_con_url_fd_set(self._eo, fd) }
+    }
+
+    /// Set a timeout in seconds for the transfer.
+    pub fn timeout_set(&self, timeout: f64) {
+        unsafe { ecore_con_url_timeout_set(self._eo, timeout) }
+    }
+
+    /// Retrieve the number of bytes received.
+    pub fn received_bytes_get(&self) -> int {
+        unsafe { ecore_con_url_received_bytes_get(self._eo) as int }
+    }
+
+    /// Retrieve the HTTP status code of the response.
+    pub fn status_code_get(&self) -> int {
+        unsafe { ecore_con_url_status_code_get(self._eo) as int }
+    }
+}
+
+impl Drop for Url {
+    fn drop(&mut self) {
+        if self._eo.is_not_null() {
+            unsafe { ecore_con_url_free(self._eo) }
+        }
+    }
+}
+
+/// Initialize the Ecore_Con library.
+pub fn init() -> int {
+    unsafe { ecore_con_init() as int }
+}
+
+/// Shut down the Ecore_Con library.
+pub fn shutdown() -> int {
+    unsafe { ecore_con_shutdown() as int }
+}
+
+/// Create a server to listen for connections.
+/// For local connections 'name' is the socket name, for remote ones it
+/// is the address to listen on (e.g. "127.0.0.1").
+pub fn server_add(ctype: ConType, name: &str, port: int) -> Server {
+    name.with_c_str(|c_name| unsafe {
+        Server { _eo: ecore_con_server_add(ctype, c_name, port as c_int, ptr::null()) }
+    })
+}
+
+/// Create a connection to the specified server.
+pub fn server_connect(ctype: ConType, name: &str, port: int) -> Server {
+    name.with_c_str(|c_name| unsafe {
+        Server { _eo: ecore_con_server_connect(ctype, c_name, port as c_int, ptr::null()) }
+    })
+}
+
+/// Add a handler called when a client connected to a server.
+pub fn event_client_add_handler_add(func: ConClientClosure) -> ecore::EventHandler {
+    _client_handler_add(unsafe { ECORE_CON_EVENT_CLIENT_ADD }, func)
+}
+
+/// Add a handler called when a client disconnected from a server.
+pub fn event_client_del_handler_add(func: ConClientClosure) -> ecore::EventHandler {
+    _client_handler_add(unsafe { ECORE_CON_EVENT_CLIENT_DEL }, func)
+}
+
+/// Add a handler for the data sent by clients to a server.
+pub fn event_client_data_handler_add(func: ConClientDataClosure) -> ecore::EventHandler {
+    let mut func = func;
+    ecore::_event_handler_closure_add(unsafe { ECORE_CON_EVENT_CLIENT_DATA },
+                                      box move |&mut: _htype: int, event: &EventInfo| {
+        let c_ev: &_CConEventClientData = unsafe { transmute(event) };
+        let data = unsafe { _bytes_from_c(c_ev.data, c_ev.size) };
+        func.call_mut((&Client { _eo: c_ev.client }, data.as_slice()))
+    })
+}
+
+/// Add a handler for the errors on client connections.
+pub fn event_client_error_handler_add(func: ConClientErrorClosure) -> ecore::EventHandler {
+    let mut func = func;
+    ecore::_event_handler_closure_add(unsafe { ECORE_CON_EVENT_CLIENT_ERROR },
+                                      box move |&mut: _htype: int, event: &EventInfo| {
+        let c_ev: &_CConEventClientError = unsafe { transmute(event) };
+        let error = unsafe { from_c_to_string(c_ev.error).unwrap_or(String::new()) };
+        func.call_mut((&Client { _eo: c_ev.client }, error.as_slice()))
+    })
+}
+
+/// Add a handler called when a connection to a server is established.
+pub fn event_server_add_handler_add(func: ConServerClosure) -> ecore::EventHandler {
+    _server_handler_add(unsafe { ECORE_CON_EVENT_SERVER_ADD }, func)
+}
+
+/// Add a handler called when a connection to a server is lost.
+pub fn event_server_del_handler_add(func: ConServerClosure) -> ecore::EventHandler {
+    _server_handler_add(unsafe { ECORE_CON_EVENT_SERVER_DEL }, func)
+}
+
+/// Add a handler for the data received from a server.
+pub fn event_server_data_handler_add(func: ConServerDataClosure) -> ecore::EventHandler {
+    let mut func = func;
+    ecore::_event_handler_closure_add(unsafe { ECORE_CON_EVENT_SERVER_DATA },
+                                      box move |&mut: _htype: int, event: &EventInfo| {
+        let c_ev: &_CConEventServerData = unsafe { transmute(event) };
+        let data = unsafe { _bytes_from_c(c_ev.data, c_ev.size) };
+        func.call_mut((&Server { _eo: c_ev.server }, data.as_slice()))
+    })
+}
+
+/// Add a handler for the errors on server connections.
+pub fn event_server_error_handler_add(func: ConServerErrorClosure) -> ecore::EventHandler {
+    let mut func = func;
+    ecore::_event_handler_closure_add(unsafe { ECORE_CON_EVENT_SERVER_ERROR },
+                                      box move |&mut: _htype: int, event: &EventInfo| {
+        let c_ev: &_CConEventServerError = unsafe { transmute(event) };
+        let error = unsafe { from_c_to_string(c_ev.error).unwrap_or(String::new()) };
+        func.call_mut((&Server { _eo: c_ev.server }, error.as_slice()))
+    })
+}
+
+/// Initialize the Ecore_Con_Url library.
+pub fn url_init() -> int {
+    unsafe { ecore_con_url_init() as int }
+}
+
+/// Shut down the Ecore_Con_Url library.
+pub fn url_shutdown() -> int {
+    unsafe { ecore_con_url_shutdown() as int }
+}
+
+/// Create a new URL transfer.
+pub fn url_new(url: &str) -> Url {
+    url.with_c_str(|c_url| unsafe {
+        Url { _eo: ecore_con_url_new(c_url) }
+    })
+}
+
+/// Add a handler for the data received by URL transfers.
+pub fn event_url_data_handler_add(func: ConUrlDataClosure) -> ecore::EventHandler {
+    let mut func = func;
+    ecore::_event_handler_closure_add(unsafe { ECORE_CON_EVENT_URL_DATA },
+                                      box move |&mut: _htype: int, event: &EventInfo| {
+        let c_ev: &_CConEventUrlData = unsafe { transmute(event) };
+        let ev = UrlEventData {
+            url_con: c_ev.url_con,
+            data: unsafe { _bytes_from_c(transmute(&c_ev.data), c_ev.size) }
+        };
+        func.call_mut((&ev,))
+    })
+}
+
+/// Add a handler for the progress of URL transfers.
+pub fn event_url_progress_handler_add(func: ConUrlProgressClosure) -> ecore::EventHandler {
+    let mut func = func;
+    ecore::_event_handler_closure_add(unsafe { ECORE_CON_EVENT_URL_PROGRESS },
+                                      box move |&mut: _htype: int, event: &EventInfo| {
+        let c_ev: &_CConEventUrlProgress = unsafe { transmute(event) };
+        let ev = UrlEventProgress {
+            url_con: c_ev.url_con,
+            down_total: c_ev.down_total,
+            down_now: c_ev.down_now,
+            up_total: c_ev.up_total,
+            up_now: c_ev.up_now
+        };
+        func.call_mut((&ev,))
+    })
+}
+
+/// Add a handler called when URL transfers complete.
+pub fn event_url_complete_handler_add(func: ConUrlCompleteClosure) -> ecore::EventHandler {
+    let mut func = func;
+    ecore::_event_handler_closure_add(unsafe { ECORE_CON_EVENT_URL_COMPLETE },
+                                      box move |&mut: _htype: int, event: &EventInfo| {
+        let c_ev: &_CConEventUrlComplete = unsafe { transmute(event) };
+        let ev = UrlEventComplete { url_con: c_ev.url_con, status: c_ev.status as int };
+        func.call_mut((&ev,))
+    })
+}
+
+fn _client_handler_add(htype: c_int, func: ConClientClosure) -> ecore::EventHandler {
+    let mut func = func;
+    ecore::_event_handler_closure_add(htype,
+                                      box move |&mut: _htype: int, event: &EventInfo| {
+        // The add and del events only hold the client pointer.
+        let client: &*EcoreConClient = unsafe { transmute(event) };
+        func.call_mut((&Client { _eo: *client },))
+    })
+}
+
+fn _server_handler_add(htype: c_int, func: ConServerClosure) -> ecore::EventHandler {
+    let mut func = func;
+    ecore::_event_handler_closure_add(htype,
+                                      box move |&mut: _htype: int, event: &EventInfo| {
+        // The add and del events only hold the server pointer.
+        let server: &*EcoreConServer = unsafe { transmute(event) };
+        func.call_mut((&Server { _eo: *server },))
+    })
+}
+
+unsafe fn _bytes_from_c(data: *c_void, size: c_int) -> Vec<u8> {
+    if data.is_null() { return Vec::new() }
+    raw::from_buf(transmute(data), size as uint)
+}
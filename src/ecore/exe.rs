@@ -0,0 +1,354 @@
+// Ecore_Exe Rust bindings for EFL.
+// Copyright (C) 2014  Luis Araujo <araujoc.luisf@gmail.com>
+
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 2.1 of the License, or (at your option) any later version.
+
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+
+/*
+ * Spawn child processes and talk to them through pipes
+ * from the ecore main loop.
+ */
+
+extern crate libc;
+
+use std::cell::Cell;
+use std::mem::transmute;
+use std::ptr;
+use std::rc::Rc;
+use std::vec::raw;
+
+use ecore::exe::libc::{c_int, c_uint, c_char, c_void, pid_t};
+use ecore;
+use eina;
+use eseful::{from_eina_to_bool, from_c_to_string, EventInfo};
+
+
+pub enum EcoreExe {}
+
+/// Flags for executing a child with its stdin and/or stdout piped back.
+pub type ExeFlags = c_uint;
+
+/// No exe flags at all.
+pub static ECORE_EXE_NONE: ExeFlags = 0;
+/// Exe Pipe Read mask.
+pub static ECORE_EXE_PIPE_READ: ExeFlags = 1;
+/// Exe Pipe Write mask.
+pub static ECORE_EXE_PIPE_WRITE: ExeFlags = 2;
+/// Exe Pipe error mask.
+pub static ECORE_EXE_PIPE_ERROR: ExeFlags = 4;
+/// Reads are buffered until a newline and split into individual lines.
+pub static ECORE_EXE_PIPE_READ_LINE_BUFFERED: ExeFlags = 8;
+/// Errors are buffered until a newline and split into individual lines.
+pub static ECORE_EXE_PIPE_ERROR_LINE_BUFFERED: ExeFlags = 16;
+/// stdout and stderr are buffered automatically.
+pub static ECORE_EXE_PIPE_AUTO: ExeFlags = 32;
+/// Ask for the child to be restarted when it dies. Ecore accepts the
+/// flag but does not implement restarting, so the child is not respawned.
+pub static ECORE_EXE_RESPAWN: ExeFlags = 64;
+/// Use /bin/sh to run the command.
+pub static ECORE_EXE_USE_SH: ExeFlags = 128;
+/// Do not use setsid() to have the executed process be its own session leader.
+pub static ECORE_EXE_NOT_LEADER: ExeFlags = 256;
+/// Makes child receive SIGTERM when parent dies.
+pub static ECORE_EXE_TERM_WITH_PARENT: ExeFlags = 512;
+
+/// Handle of a child process spawned by ecore.
+/// Ecore frees the process once its exit event has been delivered: from
+/// then on the handle does nothing and its getters return None.
+pub struct Exe {
+    _eo: *EcoreExe,
+    _alive: Rc<Cell<bool>>,
+    // Clears '_alive' on the exit event of the process.
+    _del: ecore::EventHandler
+}
+
+/// A child process was added.
+pub struct ExeEventAdd {
+    pub exe: *EcoreExe,
+    pub pid: pid_t
+}
+
+/// A child process exited.
+pub struct ExeEventDel {
+    pub exe: *EcoreExe,
+    /// The process ID of the process that exited.
+    pub pid: pid_t,
+    /// The exit code of the process.
+    pub exit_code: int,
+    /// The signal that caused the process to exit.
+    pub exit_signal: int,
+    /// Set to true if the process exited of its own accord.
+    pub exited: bool,
+    /// Set to true if the process exited due to an uncaught signal.
+    pub signalled: bool
+}
+
+/// Data (or error output) sent by a child process.
+pub struct ExeEventData {
+    pub exe: *EcoreExe,
+    /// The raw bytes received.
+    pub data: Vec<u8>,
+    /// The received data split in lines, when the process was started
+    /// with one of the line buffered flags.
+    pub lines: Vec<String>
+}
+
+/* C representation of the exe events */
+struct _CExeEventAdd {
+    exe: *EcoreExe,
+    ext_data: *c_void
+}
+
+struct _CExeEventDel {
+    pid: pid_t,
+    exit_code: c_int,
+    exe: *EcoreExe,
+    exit_signal: c_int,
+    /* 'exited' and 'signalled' bit fields */
+    _bits: eina::EinaBool
+}
+
+struct _CExeEventDataLine {
+    line: *c_char,
+    size: c_int
+}
+
+struct _CExeEventData {
+    exe: *EcoreExe,
+    data: *c_void,
+    size: c_int,
+    lines: *_CExeEventDataLine
+}
+
+pub type ExeEventAddClosure = Box<FnMut(&ExeEventAdd) -> bool + 'static>;
+pub type ExeEventDelClosure = Box<FnMut(&ExeEventDel) -> bool + 'static>;
+pub type ExeEventDataClosure = Box<FnMut(&ExeEventData) -> bool + 'static>;
+
+
+#[link(name = "ecore")]
+extern "C" {
+    static ECORE_EXE_EVENT_ADD: c_int;
+    static ECORE_EXE_EVENT_DEL: c_int;
+    static ECORE_EXE_EVENT_DATA: c_int;
+    static ECORE_EXE_EVENT_ERROR: c_int;
+    fn ecore_exe_run(exe_cmd: *c_char, data: *c_void) -> *EcoreExe;
+    fn ecore_exe_pipe_run(exe_cmd: *c_char, flags: c_uint,
+                          data: *c_void) -> *EcoreExe;
+    fn ecore_exe_send(exe: *EcoreExe, data: *c_void, size: c_int) -> eina::EinaBool;
+    fn ecore_exe_close_stdin(exe: *EcoreExe);
+    fn ecore_exe_free(exe: *EcoreExe) -> *c_void;
+    fn ecore_exe_pid_get(exe: *EcoreExe) -> pid_t;
+    fn ecore_exe_cmd_get(exe: *EcoreExe) -> *c_char;
+    fn ecore_exe_interrupt(exe: *EcoreExe);
+    fn ecore_exe_quit(exe: *EcoreExe);
+    fn ecore_exe_terminate(exe: *EcoreExe);
+    fn ecore_exe_kill(exe: *EcoreExe);
+    fn ecore_exe_signal(exe: *EcoreExe, num: c_int);
+    fn ecore_exe_hup(exe: *EcoreExe);
+}
+
+
+impl Exe {
+    /// Wrap a raw Ecore_Exe, as found in the event payloads.
+    /// This is unsafe as 'exe' must not have been freed yet, which is
+    /// not the case anymore after its exit event.
+    pub unsafe fn from_raw(exe: *EcoreExe) -> Exe {
+        _exe_new(exe)
+    }
+
+    /// Whether the process is still held by ecore.
+    pub fn is_alive(&self) -> bool {
+        self._alive.get()
+    }
+
+    /// Whether the given raw Ecore_Exe refers to this process.
+    pub fn is(&self, exe: *EcoreExe) -> bool {
+        self._eo == exe
+    }
+
+    /// Send data to the child process through its stdin pipe.
+    pub fn send(&self, data: &[u8]) -> bool {
+        if !self.is_alive() { return false }
+        unsafe {
+            from_eina_to_bool(ecore_exe_send(self._eo, transmute(data.as_ptr()),
+                                             data.len() as c_int))
+        }
+    }
+
+    /// Close the stdin pipe of the child process once the pending data
+    /// has been sent.
+    pub fn close_stdin(&self) {
+        if !self.is_alive() { return }
+        unsafe { ecore_exe_close_stdin(self._eo) }
+    }
+
+    /// Free the Ecore_Exe handle. The child process is left running.
+    pub fn free(self) {
+        if !self.is_alive() { return }
+        unsafe { ecore_exe_free(self._eo); }
+    }
+
+    /// Retrieve the process ID of the child process.
+    pub fn pid_get(&self) -> Option<pid_t> {
+        if !self.is_alive() { return None }
+        Some(unsafe { ecore_exe_pid_get(self._eo) })
+    }
+
+    /// Retrieve the command the child process was started with.
+    pub fn cmd_get(&self) -> Option<String> {
+        if !self.is_alive() { return None }
+        unsafe { from_c_to_string(ecore_exe_cmd_get(self._eo)) }
+    }
+
+    /// Send a SIGINT signal to the child process.
+    pub fn interrupt(&self) {
+        if !self.is_alive() { return }
+        unsafe { ecore_exe_interrupt(self._eo) }
+    }
+
+    /// Send a SIGQUIT signal to the child process.
+    pub fn quit(&self) {
+        if !self.is_alive() { return }
+        unsafe { ecore_exe_quit(self._eo) }
+    }
+
+    /// Send a SIGTERM signal to the child process.
+    pub fn terminate(&self) {
+        if !self.is_alive() { return }
+        unsafe { ecore_exe_terminate(self._eo) }
+    }
+
+    /// Kill the child process (SIGKILL).
+    pub fn kill(&self) {
+        if !self.is_alive() { return }
+        unsafe { ecore_exe_kill(self._eo) }
+    }
+
+    /// Send a user signal to the child process.
+    /// 'num' is 1 for SIGUSR1 and 2 for SIGUSR2.
+    pub fn signal(&self, num: int) {
+        if !self.is_alive() { return }
+        unsafe { ecore_exe_signal(self._eo, num as c_int) }
+    }
+
+    /// Send a SIGHUP signal to the child process.
+    pub fn hup(&self) {
+        if !self.is_alive() { return }
+        unsafe { ecore_exe_hup(self._eo) }
+    }
+}
+
+/// Spawn a child process.
+pub fn run(exe_cmd: &str) -> Exe {
+    exe_cmd.with_c_str(|c_exe_cmd| unsafe {
+        _exe_new(ecore_exe_run(c_exe_cmd, ptr::null()))
+    })
+}
+
+/// Spawn a child process with its stdin/out available for communication.
+/// The 'flags' are a combination of the ECORE_EXE_* values.
+pub fn pipe_run(exe_cmd: &str, flags: ExeFlags) -> Exe {
+    exe_cmd.with_c_str(|c_exe_cmd| unsafe {
+        _exe_new(ecore_exe_pipe_run(c_exe_cmd, flags, ptr::null()))
+    })
+}
+
+/* Wrap 'exe', watching for its exit event to know when ecore frees it */
+fn _exe_new(exe: *EcoreExe) -> Exe {
+    let alive = Rc::new(Cell::new(!exe.is_null()));
+    let flag = alive.clone();
+    let on_del: ecore::EcoreEventHandlerClosure =
+        box move |&mut: _htype: int, event: &EventInfo| {
+            let c_ev: &_CExeEventDel = unsafe { transmute(event) };
+            if c_ev.exe == exe { flag.set(false) }
+            // Let the other handlers see the event.
+            true
+        };
+    Exe {
+        _eo: exe, _alive: alive,
+        _del: ecore::_event_handler_closure_add(unsafe { ECORE_EXE_EVENT_DEL }, on_del)
+    }
+}
+
+/// Add a handler for the event sent when a child process is started.
+pub fn event_add_handler_add(func: ExeEventAddClosure) -> ecore::EventHandler {
+    let mut func = func;
+    ecore::_event_handler_closure_add(unsafe { ECORE_EXE_EVENT_ADD },
+                                      box move |&mut: _htype: int, event: &EventInfo| {
+        let c_ev: &_CExeEventAdd = unsafe { transmute(event) };
+        let ev = ExeEventAdd {
+            exe: c_ev.exe,
+            pid: unsafe { ecore_exe_pid_get(c_ev.exe) }
+        };
+        func.call_mut((&ev,))
+    })
+}
+
+/// Add a handler for the event sent when a child process exits.
+pub fn event_del_handler_add(func: ExeEventDelClosure) -> ecore::EventHandler {
+    let mut func = func;
+    ecore::_event_handler_closure_add(unsafe { ECORE_EXE_EVENT_DEL },
+                                      box move |&mut: _htype: int, event: &EventInfo| {
+        let c_ev: &_CExeEventDel = unsafe { transmute(event) };
+        let ev = ExeEventDel {
+            exe: c_ev.exe,
+            pid: c_ev.pid,
+            exit_code: c_ev.exit_code as int,
+            exit_signal: c_ev.exit_signal as int,
+            exited: c_ev._bits & 1 != 0,
+            signalled: c_ev._bits & 2 != 0
+        };
+        func.call_mut((&ev,))
+    })
+}
+
+/// Add a handler for the data a child process writes to its stdout.
+pub fn event_data_handler_add(func: ExeEventDataClosure) -> ecore::EventHandler {
+    _event_data_handler_add(unsafe { ECORE_EXE_EVENT_DATA }, func)
+}
+
+/// Add a handler for the data a child process writes to its stderr.
+pub fn event_error_handler_add(func: ExeEventDataClosure) -> ecore::EventHandler {
+    _event_data_handler_add(unsafe { ECORE_EXE_EVENT_ERROR }, func)
+}
+
+fn _event_data_handler_add(htype: c_int,
+                           func: ExeEventDataClosure) -> ecore::EventHandler {
+    let mut func = func;
+    ecore::_event_handler_closure_add(htype,
+                                      box move |&mut: _htype: int, event: &EventInfo| {
+        let ev = unsafe { _event_data_from_c(transmute(event)) };
+        func.call_mut((&ev,))
+    })
+}
+
+/* Copy the event data and its lines (if any) into an owned value */
+unsafe fn _event_data_from_c(c_ev: &_CExeEventData) -> ExeEventData {
+    let data: Vec<u8> =
+        if c_ev.data.is_null() { Vec::new() }
+        else { raw::from_buf(transmute(c_ev.data), c_ev.size as uint) };
+
+    let mut lines: Vec<String> = Vec::new();
+    if c_ev.lines.is_not_null() {
+        let mut l = c_ev.lines;
+        // The lines array is terminated by an entry with a NULL line.
+        while (*l).line.is_not_null() {
+            let bytes: Vec<u8> = raw::from_buf(transmute((*l).line), (*l).size as uint);
+            lines.push(String::from_utf8_lossy(bytes.as_slice()).into_string());
+            l = l.offset(1);
+        }
+    }
+
+    ExeEventData { exe: c_ev.exe, data: data, lines: lines }
+}
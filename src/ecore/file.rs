@@ -0,0 +1,302 @@
+// Ecore_File Rust bindings for EFL.
+// Copyright (C) 2014  Luis Araujo <araujoc.luisf@gmail.com>
+
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 2.1 of the License, or (at your option) any later version.
+
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+
+/*
+ * Synchronous file and path helpers, and monitors to watch
+ * files and directories for changes from the main loop.
+ */
+
+extern crate libc;
+extern crate core;
+
+use std::mem::transmute;
+
+use ecore::file::core::num::FromPrimitive;
+use ecore::file::libc::{c_int, c_uint, c_char, c_void, c_longlong, free};
+use eina;
+use eseful::{from_eina_to_bool, from_c_to_string};
+
+
+pub enum EcoreFileMonitor {}
+
+/// The kind of change reported by a file monitor.
+#[deriving(Show, FromPrimitive, PartialEq)]
+pub enum FileMonitorEvent {
+    EcoreFileEventNone,
+    /// A file was created in the monitored directory.
+    EcoreFileEventCreatedFile,
+    /// A directory was created in the monitored directory.
+    EcoreFileEventCreatedDirectory,
+    /// A file was deleted from the monitored directory.
+    EcoreFileEventDeletedFile,
+    /// A directory was deleted from the monitored directory.
+    EcoreFileEventDeletedDirectory,
+    /// The monitored file or directory itself was deleted.
+    EcoreFileEventDeletedSelf,
+    /// A file was modified.
+    EcoreFileEventModified,
+    /// A file was closed after being written.
+    EcoreFileEventClosed
+}
+
+/* The closure receives the kind of change and the path it happened to */
+pub type FileMonitorClosure = Box<FnMut(FileMonitorEvent, &str) + 'static>;
+
+/* C level callback notation */
+type _CEcoreFileMonitorCb = fn (*c_void, *EcoreFileMonitor, c_uint, *c_char);
+
+/* Heap state shared by a file monitor and its C callback */
+struct _FileMonitorState {
+    func: FileMonitorClosure
+}
+
+/// Owned handle of a file monitor.
+/// Dropping the handle stops monitoring and frees its callback state.
+pub struct FileMonitor {
+    _eo: *EcoreFileMonitor,
+    _state: *mut _FileMonitorState
+}
+
+
+#[link(name = "ecore_file")]
+extern "C" {
+    fn ecore_file_init() -> c_int;
+    fn ecore_file_shutdown() -> c_int;
+    fn ecore_file_exists(file: *c_char) -> eina::EinaBool;
+    fn ecore_file_is_dir(file: *c_char) -> eina::EinaBool;
+    fn ecore_file_mod_time(file: *c_char) -> c_longlong;
+    fn ecore_file_size(file: *c_char) -> c_longlong;
+    fn ecore_file_mkdir(dir: *c_char) -> eina::EinaBool;
+    fn ecore_file_mkpath(path: *c_char) -> eina::EinaBool;
+    fn ecore_file_rmdir(dir: *c_char) -> eina::EinaBool;
+    fn ecore_file_remove(file: *c_char) -> eina::EinaBool;
+    fn ecore_file_recursive_rm(dir: *c_char) -> eina::EinaBool;
+    fn ecore_file_cp(src: *c_char, dst: *c_char) -> eina::EinaBool;
+    fn ecore_file_mv(src: *c_char, dst: *c_char) -> eina::EinaBool;
+    fn ecore_file_symlink(src: *c_char, dest: *c_char) -> eina::EinaBool;
+    fn ecore_file_realpath(file: *c_char) -> *c_char;
+    fn ecore_file_file_get(path: *c_char) -> *c_char;
+    fn ecore_file_dir_get(path: *c_char) -> *c_char;
+    fn ecore_file_can_read(file: *c_char) -> eina::EinaBool;
+    fn ecore_file_can_write(file: *c_char) -> eina::EinaBool;
+    fn ecore_file_can_exec(file: *c_char) -> eina::EinaBool;
+    fn ecore_file_ls(dir: *c_char) -> *eina::_CEinaList;
+    fn ecore_file_app_installed(exe: *c_char) -> eina::EinaBool;
+    fn ecore_file_monitor_add(path: *c_char, func: _CEcoreFileMonitorCb,
+                              data: *c_void) -> *EcoreFileMonitor;
+    fn ecore_file_monitor_del(em: *EcoreFileMonitor);
+    fn ecore_file_monitor_path_get(em: *EcoreFileMonitor) -> *c_char;
+}
+
+
+impl FileMonitor {
+    /// Whether the monitor could be created.
+    pub fn is_valid(&self) -> bool {
+        self._eo.is_not_null()
+    }
+
+    /// Stop monitoring. This is the same as dropping the handle.
+    pub fn del(self) {}
+
+    /// Get the monitored path.
+    pub fn path_get(&self) -> String {
+        unsafe {
+            from_c_to_string(ecore_file_monitor_path_get(self._eo)).unwrap_or(String::new())
+        }
+    }
+}
+
+#[unsafe_destructor]
+impl Drop for FileMonitor {
+    fn drop(&mut self) {
+        unsafe {
+            if self._eo.is_not_null() { ecore_file_monitor_del(self._eo) }
+            drop(transmute::<*mut _FileMonitorState, Box<_FileMonitorState>>(self._state))
+        }
+    }
+}
+
+/// Initialize the Ecore_File library.
+pub fn init() -> int {
+    unsafe { ecore_file_init() as int }
+}
+
+/// Shut down the Ecore_File library.
+pub fn shutdown() -> int {
+    unsafe { ecore_file_shutdown() as int }
+}
+
+/// Check if the given file exists.
+pub fn exists(file: &str) -> bool {
+    _bool_call(file, ecore_file_exists)
+}
+
+/// Check if the given file is a directory.
+pub fn is_dir(file: &str) -> bool {
+    _bool_call(file, ecore_file_is_dir)
+}
+
+/// Get the time of the last modification to the given file.
+pub fn mod_time(file: &str) -> i64 {
+    file.with_c_str(|c_file| unsafe { ecore_file_mod_time(c_file) as i64 })
+}
+
+/// Get the size of the given file, in bytes.
+pub fn size(file: &str) -> i64 {
+    file.with_c_str(|c_file| unsafe { ecore_file_size(c_file) as i64 })
+}
+
+/// Create a new directory.
+pub fn mkdir(dir: &str) -> bool {
+    _bool_call(dir, ecore_file_mkdir)
+}
+
+/// Create a complete path, creating every missing directory.
+pub fn mkpath(path: &str) -> bool {
+    _bool_call(path, ecore_file_mkpath)
+}
+
+/// Delete the given empty directory.
+pub fn rmdir(dir: &str) -> bool {
+    _bool_call(dir, ecore_file_rmdir)
+}
+
+/// Remove the given file or directory.
+pub fn remove(file: &str) -> bool {
+    _bool_call(file, ecore_file_remove)
+}
+
+/// Delete the given directory and all its contents.
+pub fn recursive_rm(dir: &str) -> bool {
+    _bool_call(dir, ecore_file_recursive_rm)
+}
+
+/// Copy the file 'src' to 'dst'.
+pub fn cp(src: &str, dst: &str) -> bool {
+    _bool_call2(src, dst, ecore_file_cp)
+}
+
+/// Move the file 'src' to 'dst'.
+pub fn mv(src: &str, dst: &str) -> bool {
+    _bool_call2(src, dst, ecore_file_mv)
+}
+
+/// Create a symbolic link 'dest' pointing to 'src'.
+pub fn symlink(src: &str, dest: &str) -> bool {
+    _bool_call2(src, dest, ecore_file_symlink)
+}
+
+/// Get the canonicalized absolute path name.
+/// An empty string is returned if the file does not exist.
+pub fn realpath(file: &str) -> String {
+    file.with_c_str(|c_file| unsafe {
+        _string_from_c_free(ecore_file_realpath(c_file))
+    })
+}
+
+/// Get the filename from a given path.
+pub fn file_get(path: &str) -> String {
+    path.with_c_str(|c_path| unsafe {
+        // The result points inside 'c_path', so copy it while it is alive.
+        from_c_to_string(ecore_file_file_get(c_path)).unwrap_or(String::new())
+    })
+}
+
+/// Get the directory where the file specified by 'path' is stored.
+pub fn dir_get(path: &str) -> String {
+    path.with_c_str(|c_path| unsafe {
+        _string_from_c_free(ecore_file_dir_get(c_path))
+    })
+}
+
+/// Check if the file can be read.
+pub fn can_read(file: &str) -> bool {
+    _bool_call(file, ecore_file_can_read)
+}
+
+/// Check if the file can be written.
+pub fn can_write(file: &str) -> bool {
+    _bool_call(file, ecore_file_can_write)
+}
+
+/// Check if the file can be executed.
+pub fn can_exec(file: &str) -> bool {
+    _bool_call(file, ecore_file_can_exec)
+}
+
+/// Get the sorted list of the files in the given directory.
+pub fn ls(dir: &str) -> Vec<String> {
+    dir.with_c_str(|c_dir| unsafe {
+        let list: *mut eina::_EinaList<c_char> = transmute(ecore_file_ls(c_dir));
+        let mut files: Vec<String> = Vec::new();
+        for name in eina::EinaList::new(list) {
+            files.push(_string_from_c_free(name as *c_char));
+        }
+        eina::list_free(list);
+        files
+    })
+}
+
+/// Check if the given executable is installed in the user's PATH.
+pub fn app_installed(exe: &str) -> bool {
+    _bool_call(exe, ecore_file_app_installed)
+}
+
+/// Monitor the given path for changes.
+/// The closure is called with the kind of change and the path it
+/// happened to, for as long as the returned handle lives.
+pub fn monitor_add(path: &str, func: FileMonitorClosure) -> FileMonitor {
+    path.with_c_str(|c_path| unsafe {
+        let state: *mut _FileMonitorState = transmute(box _FileMonitorState { func: func });
+        let c_func: _CEcoreFileMonitorCb = transmute(_monitor_cb);
+        FileMonitor {
+            _eo: ecore_file_monitor_add(c_path, c_func, transmute(state)),
+            _state: state
+        }
+    })
+}
+
+/* Run the monitor closure stored in 'data' with the typed event */
+extern "C" fn _monitor_cb(data: *c_void, _em: *EcoreFileMonitor,
+                          event: c_uint, path: *c_char) {
+    unsafe {
+        let state: &mut _FileMonitorState = transmute(data);
+        let ev: FileMonitorEvent = match FromPrimitive::from_u32(event) {
+            None => EcoreFileEventNone, Some(ev) => ev
+        };
+        let path = from_c_to_string(path).unwrap_or(String::new());
+        state.func.call_mut((ev, path.as_slice()))
+    }
+}
+
+fn _bool_call(file: &str, func: unsafe extern "C" fn (*c_char) -> eina::EinaBool) -> bool {
+    file.with_c_str(|c_file| unsafe { from_eina_to_bool(func(c_file)) })
+}
+
+fn _bool_call2(src: &str, dst: &str,
+               func: unsafe extern "C" fn (*c_char, *c_char) -> eina::EinaBool) -> bool {
+    src.with_c_str(|c_src| unsafe {
+        dst.with_c_str(|c_dst| from_eina_to_bool(func(c_src, c_dst)))
+    })
+}
+
+/* Copy a string allocated by ecore_file, then free it */
+unsafe fn _string_from_c_free(s: *c_char) -> String {
+    let string = from_c_to_string(s).unwrap_or(String::new());
+    if s.is_not_null() { free(transmute(s)) }
+    string
+}
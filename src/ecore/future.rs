@@ -0,0 +1,270 @@
+// Futures driven by the ecore main loop for EFL Rust bindings.
+// Copyright (C) 2014  Luis Araujo <araujoc.luisf@gmail.com>
+
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 2.1 of the License, or (at your option) any later version.
+
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+
+/*
+ * A small poll based executor running on the ecore main loop.
+ *
+ * The Rust language targeted by these bindings has no 'async fn' support,
+ * so asynchronous operations are written as sequential chains of futures
+ * with 'and_then' and 'map' instead of nested callbacks.
+ */
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::mem::transmute;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, SeqCst};
+
+use ecore;
+
+
+/// The state of a future after being polled.
+pub enum Poll<T> {
+    /// The future completed with the given value.
+    Ready(T),
+    /// The future is waiting, and will wake its task once it can progress.
+    Pending
+}
+
+/// An asynchronous computation driven by an Executor.
+pub trait Future<T> {
+    /// Try to make progress. When returning Pending, the future must make
+    /// sure 'waker' is called once it can progress again.
+    fn poll(&mut self, waker: &Waker) -> Poll<T>;
+}
+
+pub type BoxFuture<T> = Box<Future<T> + 'static>;
+
+/// Wakes up a task of an executor.
+/// It can be sent to other threads, waking always happens on the main loop.
+/// Waking a task of a dropped executor does nothing.
+#[deriving(Clone)]
+pub struct Waker {
+    _executor: uint,
+    _alive: Arc<AtomicBool>,
+    _id: uint
+}
+
+/// Executor polling futures from the ecore main loop.
+/// Dropping it drops its pending tasks.
+pub struct Executor {
+    tasks: RefCell<HashMap<uint, BoxFuture<()>>>,
+    next_id: Cell<uint>,
+    /* Cleared on drop, so that wakers still around stop using it */
+    alive: Arc<AtomicBool>
+}
+
+/// A future completed through its Completer.
+pub struct Oneshot<T> {
+    _shared: Rc<RefCell<_OneshotState<T>>>
+}
+
+/// The completing side of a Oneshot future.
+pub struct Completer<T> {
+    _shared: Rc<RefCell<_OneshotState<T>>>
+}
+
+struct _OneshotState<T> {
+    value: Option<T>,
+    waker: Option<Waker>
+}
+
+/// Future created by 'timer', ready once the timer expired.
+pub struct TimerFuture {
+    _inv: f64,
+    _timer: Option<ecore::Timer>,
+    _done: Rc<Cell<bool>>
+}
+
+/* Future already holding its value */
+struct _Ready<T> {
+    value: Option<T>
+}
+
+/* Future mapping the value of another one */
+struct _Map<T, U> {
+    fut: BoxFuture<T>,
+    func: Option<proc(T) -> U>
+}
+
+/* Future chaining another future produced from the value of a first one */
+enum _AndThen<T, U> {
+    _First(BoxFuture<T>, Option<proc(T) -> BoxFuture<U>>),
+    _Second(BoxFuture<U>)
+}
+
+
+impl Waker {
+    /// Schedule the task to be polled again on the main loop.
+    pub fn wake(&self) {
+        let (executor, alive, id) = (self._executor, self._alive.clone(), self._id);
+        ecore::main_loop_thread_safe_call_async(proc() {
+            /* The executor is dropped on the main loop as well,
+             * so it cannot go away between the check and the poll */
+            if !alive.load(SeqCst) { return }
+            let e: &Executor = unsafe { transmute(executor) };
+            e.poll_task(id);
+        })
+    }
+}
+
+impl Executor {
+    /// Create a new executor.
+    /// It is boxed so that wakers can keep referring to it.
+    pub fn new() -> Box<Executor> {
+        box Executor {
+            tasks: RefCell::new(HashMap::new()),
+            next_id: Cell::new(0),
+            alive: Arc::new(AtomicBool::new(true))
+        }
+    }
+
+    /// Spawn a task, to be first polled once the main loop runs.
+    pub fn spawn(&self, fut: BoxFuture<()>) {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        self.tasks.borrow_mut().insert(id, fut);
+        self._waker(id).wake();
+    }
+
+    /// Number of tasks not yet completed.
+    pub fn pending_get(&self) -> uint {
+        self.tasks.borrow().len()
+    }
+
+    /* Poll the task, keeping it around only while it is pending.
+     * Wakeups for completed tasks are ignored. */
+    fn poll_task(&self, id: uint) {
+        let task = self.tasks.borrow_mut().pop(&id);
+        match task {
+            None => (),
+            Some(mut fut) => match fut.poll(&self._waker(id)) {
+                Ready(()) => (),
+                Pending => { self.tasks.borrow_mut().insert(id, fut); }
+            }
+        }
+    }
+
+    fn _waker(&self, id: uint) -> Waker {
+        Waker { _executor: unsafe { transmute(self) }, _alive: self.alive.clone(), _id: id }
+    }
+}
+
+impl Drop for Executor {
+    fn drop(&mut self) {
+        self.alive.store(false, SeqCst)
+    }
+}
+
+impl<T> Completer<T> {
+    /// Complete the future with 'value', waking its task.
+    pub fn complete(self, value: T) {
+        let mut state = self._shared.borrow_mut();
+        state.value = Some(value);
+        match state.waker.take() {
+            None => (),
+            Some(waker) => waker.wake()
+        }
+    }
+}
+
+impl<T> Future<T> for Oneshot<T> {
+    fn poll(&mut self, waker: &Waker) -> Poll<T> {
+        let mut state = self._shared.borrow_mut();
+        match state.value.take() {
+            Some(value) => Ready(value),
+            None => { state.waker = Some(waker.clone()); Pending }
+        }
+    }
+}
+
+impl Future<()> for TimerFuture {
+    fn poll(&mut self, waker: &Waker) -> Poll<()> {
+        if self._done.get() { return Ready(()) }
+        if self._timer.is_none() {
+            let done = self._done.clone();
+            let waker = waker.clone();
+            self._timer = Some(ecore::timer_add_closure(self._inv, box move |&mut:| {
+                done.set(true);
+                waker.wake();
+                false
+            }));
+        }
+        Pending
+    }
+}
+
+impl<T> Future<T> for _Ready<T> {
+    fn poll(&mut self, _waker: &Waker) -> Poll<T> {
+        match self.value.take() {
+            Some(value) => Ready(value),
+            None => fail!("Future polled after completion")
+        }
+    }
+}
+
+impl<T, U> Future<U> for _Map<T, U> {
+    fn poll(&mut self, waker: &Waker) -> Poll<U> {
+        match self.fut.poll(waker) {
+            Pending => Pending,
+            Ready(value) => Ready((self.func.take().unwrap())(value))
+        }
+    }
+}
+
+impl<T: 'static, U: 'static> Future<U> for _AndThen<T, U> {
+    fn poll(&mut self, waker: &Waker) -> Poll<U> {
+        let next = match *self {
+            _Second(ref mut fut) => return fut.poll(waker),
+            _First(ref mut fut, ref mut func) => match fut.poll(waker) {
+                Pending => return Pending,
+                Ready(value) => (func.take().unwrap())(value)
+            }
+        };
+        *self = _Second(next);
+        self.poll(waker)
+    }
+}
+
+
+/// Create a future and the Completer used to complete it.
+pub fn oneshot<T>() -> (Oneshot<T>, Completer<T>) {
+    let shared = Rc::new(RefCell::new(_OneshotState { value: None, waker: None }));
+    (Oneshot { _shared: shared.clone() }, Completer { _shared: shared })
+}
+
+/// A future that is immediately ready with 'value'.
+pub fn ready<T: 'static>(value: T) -> BoxFuture<T> {
+    box _Ready { value: Some(value) }
+}
+
+/// A future ready after 'inv' seconds, counted from its first poll.
+pub fn timer(inv: f64) -> BoxFuture<()> {
+    box TimerFuture { _inv: inv, _timer: None, _done: Rc::new(Cell::new(false)) }
+}
+
+/// Transform the value of 'fut' with 'func'.
+pub fn map<T: 'static, U: 'static>(fut: BoxFuture<T>, func: proc(T) -> U) -> BoxFuture<U> {
+    box _Map { fut: fut, func: Some(func) }
+}
+
+/// Run the future returned by 'func' after 'fut' completes.
+pub fn and_then<T: 'static, U: 'static>(fut: BoxFuture<T>,
+                                        func: proc(T) -> BoxFuture<U>) -> BoxFuture<U> {
+    box _First(fut, Some(func))
+}
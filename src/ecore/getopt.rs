@@ -0,0 +1,491 @@
+// Ecore_Getopt Rust bindings for EFL.
+// Copyright (C) 2014  Luis Araujo <araujoc.luisf@gmail.com>
+
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 2.1 of the License, or (at your option) any later version.
+
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+
+/*
+ * Command line parsing with Ecore_Getopt.
+ *
+ * The options are described with the Parser builder, which lays out
+ * the Ecore_Getopt descriptor expected by C when parsing, so every
+ * EFL tool gets the same behaviour and help output.
+ */
+
+extern crate libc;
+
+use std::c_str::CString;
+use std::collections::HashMap;
+use std::io::stdio;
+use std::mem::{transmute, size_of};
+use std::ptr;
+
+use ecore::getopt::libc::{c_int, c_uint, c_char, c_void, c_short, c_long,
+                          c_ushort, c_ulong, FILE, dup, close, fdopen, fclose};
+use ecore;
+use eina;
+use eseful::from_c_to_string;
+
+/* Initial value of the slots of the stored options: a slot still
+ * holding it after parsing was not set on the command line */
+static _UNSET: u64 = 0x7ff85a5aa5a5a5a5;
+
+
+/// Types of the values stored by 'Parser::store'.
+pub enum GetoptType {
+    GetoptTypeStr,
+    GetoptTypeBool,
+    GetoptTypeShort,
+    GetoptTypeInt,
+    GetoptTypeLong,
+    GetoptTypeUshort,
+    GetoptTypeUint,
+    GetoptTypeUlong,
+    GetoptTypeDouble
+}
+
+/// A parsed option value.
+#[deriving(Show, Clone)]
+pub enum GetoptValue {
+    GetoptValueStr(String),
+    GetoptValueBool(bool),
+    GetoptValueInt(int),
+    GetoptValueDouble(f64),
+    GetoptValueList(Vec<String>)
+}
+
+/// Builder describing the command line options of a program.
+pub struct Parser {
+    prog: String,
+    usage: Option<String>,
+    version: Option<String>,
+    copyright: Option<String>,
+    license: Option<String>,
+    description: Option<String>,
+    strict: bool,
+    opts: Vec<_Opt>
+}
+
+/// Values obtained by 'Parser::parse'.
+pub struct GetoptValues {
+    values: HashMap<String, GetoptValue>,
+    /// Set when an action such as help or version was triggered, in which
+    /// case the program is expected to exit.
+    pub quit: bool,
+    /// The remaining (non option) arguments.
+    pub args: Vec<String>
+}
+
+/* Ecore_Getopt_Action */
+static ACTION_STORE: c_uint = 0;
+static ACTION_STORE_TRUE: c_uint = 2;
+static ACTION_CHOICE: c_uint = 4;
+static ACTION_APPEND: c_uint = 5;
+static ACTION_COUNT: c_uint = 6;
+static ACTION_HELP: c_uint = 8;
+static ACTION_VERSION: c_uint = 9;
+static ACTION_COPYRIGHT: c_uint = 10;
+static ACTION_LICENSE: c_uint = 11;
+
+/* Ecore_Getopt_Desc_Arg_Requirement */
+static ARG_REQUIREMENT_YES: c_uint = 1;
+
+/* Rust side description of an option */
+struct _Opt {
+    shortname: char,
+    longname: String,
+    help: String,
+    metavar: Option<String>,
+    action: c_uint,
+    vtype: c_uint,
+    choices: Vec<String>
+}
+
+/* C representation of the descriptors.
+ * The header is followed by the array of descriptors, ended by a
+ * sentinel (zeroed) descriptor. */
+struct _CGetoptHeader {
+    prog: *c_char,
+    usage: *c_char,
+    version: *c_char,
+    copyright: *c_char,
+    license: *c_char,
+    description: *c_char,
+    strict: eina::EinaBool
+}
+
+struct _CGetoptDesc {
+    shortname: c_char,
+    longname: *c_char,
+    help: *c_char,
+    metavar: *c_char,
+    action: c_uint,
+    /* Union of the action parameters, the largest being a callback */
+    action_param: [u64, ..4]
+}
+
+struct _CGetoptDescStore {
+    vtype: c_uint,
+    arg_req: c_uint,
+    def: u64
+}
+
+#[link(name = "ecore")]
+extern "C" {
+    fn ecore_getopt_parse(parser: *c_void, values: *mut *mut c_void,
+                          argc: c_int, argv: **c_char) -> c_int;
+    fn ecore_getopt_help(fp: *mut FILE, info: *c_void);
+    fn ecore_getopt_list_free(list: *eina::_CEinaList) -> *eina::_CEinaList;
+}
+
+
+impl Parser {
+    /// Start the description of the options of program 'prog'.
+    pub fn new(prog: &str) -> Parser {
+        Parser {
+            prog: prog.to_string(),
+            usage: None, version: None, copyright: None,
+            license: None, description: None,
+            strict: false,
+            opts: Vec::new()
+        }
+    }
+
+    /// Set the usage line. "%prog" is replaced with the program name.
+    pub fn usage(mut self, usage: &str) -> Parser {
+        self.usage = Some(usage.to_string()); self
+    }
+
+    /// Set the version shown by the version action.
+    pub fn version(mut self, version: &str) -> Parser {
+        self.version = Some(version.to_string()); self
+    }
+
+    /// Set the copyright shown by the copyright action.
+    pub fn copyright(mut self, copyright: &str) -> Parser {
+        self.copyright = Some(copyright.to_string()); self
+    }
+
+    /// Set the license shown by the license action.
+    pub fn license(mut self, license: &str) -> Parser {
+        self.license = Some(license.to_string()); self
+    }
+
+    /// Set the description shown in the help.
+    pub fn description(mut self, description: &str) -> Parser {
+        self.description = Some(description.to_string()); self
+    }
+
+    /// Make parsing fail on unknown options and extra arguments errors.
+    pub fn strict(mut self, strict: bool) -> Parser {
+        self.strict = strict; self
+    }
+
+    /// Store the argument of the option, converted to 'vtype'.
+    pub fn store(self, shortname: char, longname: &str, help: &str,
+                 metavar: &str, vtype: GetoptType) -> Parser {
+        self._opt(shortname, longname, help, Some(metavar), ACTION_STORE,
+                  vtype as c_uint, Vec::new())
+    }
+
+    /// Store true if the option is given.
+    pub fn store_true(self, shortname: char, longname: &str, help: &str) -> Parser {
+        self._opt(shortname, longname, help, None, ACTION_STORE_TRUE, 0, Vec::new())
+    }
+
+    /// Count the number of times the option is given.
+    pub fn count(self, shortname: char, longname: &str, help: &str) -> Parser {
+        self._opt(shortname, longname, help, None, ACTION_COUNT, 0, Vec::new())
+    }
+
+    /// Collect the arguments of every occurrence of the option.
+    pub fn append(self, shortname: char, longname: &str, help: &str,
+                  metavar: &str) -> Parser {
+        self._opt(shortname, longname, help, Some(metavar), ACTION_APPEND,
+                  GetoptTypeStr as c_uint, Vec::new())
+    }
+
+    /// Store the argument of the option, which must be one of 'choices'.
+    pub fn choice(self, shortname: char, longname: &str, help: &str,
+                  metavar: &str, choices: &[&str]) -> Parser {
+        let choices: Vec<String> = choices.iter().map(|c| c.to_string()).collect();
+        self._opt(shortname, longname, help, Some(metavar), ACTION_CHOICE, 0, choices)
+    }
+
+    /// Show the help and quit when the option is given.
+    pub fn help(self, shortname: char, longname: &str) -> Parser {
+        self._opt(shortname, longname, "show this message.", None,
+                  ACTION_HELP, 0, Vec::new())
+    }
+
+    /// Show the version and quit when the option is given.
+    pub fn version_action(self, shortname: char, longname: &str) -> Parser {
+        self._opt(shortname, longname, "show program version.", None,
+                  ACTION_VERSION, 0, Vec::new())
+    }
+
+    /// Show the copyright and quit when the option is given.
+    pub fn copyright_action(self, shortname: char, longname: &str) -> Parser {
+        self._opt(shortname, longname, "show copyright.", None,
+                  ACTION_COPYRIGHT, 0, Vec::new())
+    }
+
+    /// Show the license and quit when the option is given.
+    pub fn license_action(self, shortname: char, longname: &str) -> Parser {
+        self._opt(shortname, longname, "show license.", None,
+                  ACTION_LICENSE, 0, Vec::new())
+    }
+
+    fn _opt(mut self, shortname: char, longname: &str, help: &str,
+            metavar: Option<&str>, action: c_uint, vtype: c_uint,
+            choices: Vec<String>) -> Parser {
+        self.opts.push(_Opt {
+            shortname: shortname,
+            longname: longname.to_string(),
+            help: help.to_string(),
+            metavar: metavar.map(|m| m.to_string()),
+            action: action,
+            vtype: vtype,
+            choices: choices
+        });
+        self
+    }
+
+    /// Parse the command line 'args' (usually 'std::os::args()').
+    /// The arguments are also set as the ecore application arguments.
+    /// Returns None if the command line could not be parsed.
+    pub fn parse(&self, args: Vec<String>) -> Option<GetoptValues> {
+        ecore::app_args_set(args.len(), args);
+        self.parse_app_args()
+    }
+
+    /// Parse the arguments set with 'ecore::app_args_set' (or by
+    /// 'elementary::init').
+    pub fn parse_app_args(&self) -> Option<GetoptValues> {
+        let n = self.opts.len();
+        // Counts, flags and lists are updated in place, so start from 0.
+        let mut slots: Vec<u64> = self.opts.iter()
+            .map(|opt| if opt.action == ACTION_STORE { _UNSET } else { 0u64 })
+            .collect();
+        let mut values: Vec<*mut c_void> = Vec::with_capacity(n + 1);
+        for i in range(0, n) {
+            values.push(unsafe { transmute(slots.get_mut(i)) });
+        }
+        values.push(ptr::mut_null());
+
+        let mut cstrings: Vec<CString> = Vec::new();
+        let mut choices: Vec<Vec<*c_char>> = Vec::new();
+        let buf = self._descriptor(&mut cstrings, &mut choices);
+
+        let index = unsafe {
+            ecore_getopt_parse(transmute(buf.as_ptr()), values.as_mut_ptr(), 0, ptr::null())
+        };
+        if index < 0 { return None }
+
+        let mut result = GetoptValues {
+            values: HashMap::new(),
+            quit: false,
+            args: ecore::app_args_get().move_iter().skip(index as uint).collect()
+        };
+        for (opt, slot) in self.opts.iter().zip(slots.iter()) {
+            unsafe { _collect_value(opt, slot, &mut result) }
+        }
+        Some(result)
+    }
+
+    /// Print the help to the standard output.
+    pub fn print_help(&self) {
+        let mut cstrings: Vec<CString> = Vec::new();
+        let mut choices: Vec<Vec<*c_char>> = Vec::new();
+        let buf = self._descriptor(&mut cstrings, &mut choices);
+        /* Write through a duplicate of the stdout fd, closed once done,
+         * after what Rust already buffered */
+        stdio::flush();
+        "w".with_c_str(|c_mode| unsafe {
+            let fd = dup(1);
+            if fd < 0 { return }
+            let fp = fdopen(fd, c_mode);
+            if fp.is_null() { close(fd); return }
+            ecore_getopt_help(fp, transmute(buf.as_ptr()));
+            fclose(fp);
+        })
+    }
+
+    /* Lay out the Ecore_Getopt descriptor in a buffer.
+     * The strings it points to are kept alive in 'cstrings' and 'choices'. */
+    fn _descriptor(&self, cstrings: &mut Vec<CString>,
+                   choices: &mut Vec<Vec<*c_char>>) -> Vec<u64> {
+        let header_size = size_of::<_CGetoptHeader>();
+        let desc_size = size_of::<_CGetoptDesc>();
+        let total = header_size + (self.opts.len() + 1) * desc_size;
+        // Zeroed, so the last descriptor is the sentinel.
+        let mut buf: Vec<u64> = Vec::from_elem(total / 8 + 1, 0u64);
+
+        unsafe {
+            let header: *mut _CGetoptHeader = transmute(buf.as_mut_ptr());
+            *header = _CGetoptHeader {
+                prog: _keep(cstrings, Some(&self.prog)),
+                usage: _keep(cstrings, self.usage.as_ref()),
+                version: _keep(cstrings, self.version.as_ref()),
+                copyright: _keep(cstrings, self.copyright.as_ref()),
+                license: _keep(cstrings, self.license.as_ref()),
+                description: _keep(cstrings, self.description.as_ref()),
+                strict: self.strict as eina::EinaBool
+            };
+
+            let descs: *mut _CGetoptDesc =
+                transmute(transmute::<_, *mut u8>(buf.as_mut_ptr()).offset(header_size as int));
+            for (i, opt) in self.opts.iter().enumerate() {
+                let desc: &mut _CGetoptDesc = transmute(descs.offset(i as int));
+                desc.shortname = if opt.shortname == '\0' { 0 } else { opt.shortname as c_char };
+                desc.longname = _keep(cstrings, Some(&opt.longname));
+                desc.help = _keep(cstrings, Some(&opt.help));
+                desc.metavar = _keep(cstrings, opt.metavar.as_ref());
+                desc.action = opt.action;
+
+                if opt.action == ACTION_STORE {
+                    let store: &mut _CGetoptDescStore = transmute(&mut desc.action_param);
+                    store.vtype = opt.vtype;
+                    store.arg_req = ARG_REQUIREMENT_YES;
+                } else if opt.action == ACTION_APPEND {
+                    let append_type: &mut c_uint = transmute(&mut desc.action_param);
+                    *append_type = opt.vtype;
+                } else if opt.action == ACTION_CHOICE {
+                    // NULL terminated array of the choices.
+                    let mut c_choices: Vec<*c_char> =
+                        opt.choices.iter().map(|c| _keep(cstrings, Some(c))).collect();
+                    c_choices.push(ptr::null());
+                    let param: &mut *c_char = transmute(&mut desc.action_param);
+                    *param = transmute(c_choices.as_ptr());
+                    choices.push(c_choices);
+                }
+            }
+        }
+        buf
+    }
+}
+
+impl GetoptValues {
+    /// Get the raw value of the option named 'longname'.
+    pub fn get<'r>(&'r self, longname: &str) -> Option<&'r GetoptValue> {
+        self.values.find(&longname.to_string())
+    }
+
+    /// Get the string value of an option, if it was given.
+    pub fn str_get(&self, longname: &str) -> Option<String> {
+        match self.get(longname) {
+            Some(&GetoptValueStr(ref s)) => Some(s.clone()), _ => None
+        }
+    }
+
+    /// Get the integer value of an option (or its count), if it was given.
+    pub fn int_get(&self, longname: &str) -> Option<int> {
+        match self.get(longname) {
+            Some(&GetoptValueInt(i)) => Some(i), _ => None
+        }
+    }
+
+    /// Get the floating point value of an option, if it was given.
+    pub fn double_get(&self, longname: &str) -> Option<f64> {
+        match self.get(longname) {
+            Some(&GetoptValueDouble(d)) => Some(d), _ => None
+        }
+    }
+
+    /// Whether a boolean option was given.
+    pub fn bool_get(&self, longname: &str) -> bool {
+        match self.get(longname) {
+            Some(&GetoptValueBool(b)) => b, _ => false
+        }
+    }
+
+    /// Get the number of times a counted option was given.
+    pub fn count_get(&self, longname: &str) -> int {
+        self.int_get(longname).unwrap_or(0)
+    }
+
+    /// Get the arguments collected by an appended option.
+    pub fn list_get(&self, longname: &str) -> Vec<String> {
+        match self.get(longname) {
+            Some(&GetoptValueList(ref l)) => l.clone(), _ => Vec::new()
+        }
+    }
+}
+
+/* Convert the C value of 'opt' stored in 'slot', if it was set */
+unsafe fn _collect_value(opt: &_Opt, slot: &u64, result: &mut GetoptValues) {
+    let p: *c_void = transmute(slot);
+    let action = opt.action;
+    let value =
+        if action == ACTION_STORE_TRUE {
+            if *(p as *eina::EinaBool) != 0 { Some(GetoptValueBool(true)) } else { None }
+        } else if action == ACTION_COUNT {
+            let count = *(p as *c_int) as int;
+            if count > 0 { Some(GetoptValueInt(count)) } else { None }
+        } else if action == ACTION_CHOICE {
+            _str_value(*(p as **c_char))
+        } else if action == ACTION_APPEND {
+            let list: *eina::_CEinaList = *(p as **eina::_CEinaList);
+            let items: *mut eina::_EinaList<c_char> = transmute(list);
+            let strings: Vec<String> = eina::EinaList::new(items)
+                .map(|s| from_c_to_string(s as *c_char).unwrap_or(String::new()))
+                .collect();
+            ecore_getopt_list_free(list);
+            Some(GetoptValueList(strings))
+        } else if action == ACTION_STORE && *slot == _UNSET {
+            None
+        } else if action == ACTION_STORE {
+            match opt.vtype {
+                0 => _str_value(*(p as **c_char)),
+                1 => Some(GetoptValueBool(*(p as *eina::EinaBool) != 0)),
+                2 => Some(GetoptValueInt(*(p as *c_short) as int)),
+                3 => Some(GetoptValueInt(*(p as *c_int) as int)),
+                4 => Some(GetoptValueInt(*(p as *c_long) as int)),
+                5 => Some(GetoptValueInt(*(p as *c_ushort) as int)),
+                6 => Some(GetoptValueInt(*(p as *c_uint) as int)),
+                7 => Some(GetoptValueInt(*(p as *c_ulong) as int)),
+                _ => Some(GetoptValueDouble(*(p as *f64)))
+            }
+        } else {
+            // Help, version, copyright and license actions ask to quit.
+            if *(p as *eina::EinaBool) != 0 { result.quit = true }
+            None
+        };
+
+    match value {
+        None => (),
+        Some(v) => {
+            let name = if opt.longname.is_empty() { opt.shortname.to_string() }
+                       else { opt.longname.clone() };
+            result.values.insert(name, v);
+        }
+    }
+}
+
+unsafe fn _str_value(s: *c_char) -> Option<GetoptValue> {
+    from_c_to_string(s).map(|s| GetoptValueStr(s))
+}
+
+/* Keep a C copy of 's' alive in 'cstrings', returning its pointer */
+fn _keep(cstrings: &mut Vec<CString>, s: Option<&String>) -> *c_char {
+    match s {
+        None => ptr::null(),
+        Some(s) => {
+            let c = s.to_c_str();
+            let p = c.as_ptr();
+            cstrings.push(c);
+            p
+        }
+    }
+}
@@ -0,0 +1,324 @@
+// Ecore_Ipc Rust bindings for EFL.
+// Copyright (C) 2014  Luis Araujo <araujoc.luisf@gmail.com>
+
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 2.1 of the License, or (at your option) any later version.
+
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+
+/*
+ * Message based IPC between EFL processes.
+ * Each message carries a major/minor opcode, reference numbers used to
+ * match requests with their responses, and an optional payload.
+ */
+
+extern crate libc;
+
+use std::mem::transmute;
+use std::ptr;
+use std::vec::raw;
+
+use ecore::ipc::libc::{c_int, c_uint, c_char, c_void};
+use ecore;
+use eina;
+use eseful::{from_eina_to_bool, EventInfo};
+
+
+pub enum EcoreIpcServer {}
+pub enum EcoreIpcClient {}
+
+/// Types for an ecore_ipc server or client connection.
+pub type IpcType = c_uint;
+
+/// Socket in ~/.ecore
+pub static ECORE_IPC_LOCAL_USER: IpcType = 0;
+/// Socket in /tmp
+pub static ECORE_IPC_LOCAL_SYSTEM: IpcType = 1;
+/// Remote server using TCP
+pub static ECORE_IPC_REMOTE_SYSTEM: IpcType = 2;
+/// Use SSL
+pub static ECORE_IPC_USE_SSL: IpcType = 16;
+/// Disable all types of proxy on the server
+pub static ECORE_IPC_NO_PROXY: IpcType = 32;
+
+/// Handle of an IPC server, either listening (from 'server_add') or
+/// connected to (from 'server_connect').
+pub struct Server {
+    _eo: *EcoreIpcServer
+}
+
+/// Handle of a client connected to a listening IPC server.
+pub struct Client {
+    _eo: *EcoreIpcClient
+}
+
+/// A message exchanged between IPC servers and clients.
+pub struct Message {
+    /// The message major opcode.
+    pub major: int,
+    /// The message minor opcode.
+    pub minor: int,
+    /// The message reference number.
+    pub reference: int,
+    /// The reference number of the message this one refers to.
+    pub ref_to: int,
+    /// Requires response.
+    pub response: int,
+    /// The message payload.
+    pub data: Vec<u8>
+}
+
+pub type IpcClientClosure = Box<FnMut(&Client) -> bool + 'static>;
+pub type IpcClientDataClosure = Box<FnMut(&Client, &Message) -> bool + 'static>;
+pub type IpcServerClosure = Box<FnMut(&Server) -> bool + 'static>;
+pub type IpcServerDataClosure = Box<FnMut(&Server, &Message) -> bool + 'static>;
+
+/* C representation of the data events.
+ * The add and del events only hold the client or server. */
+struct _CIpcEventData {
+    /* Either the client or the server */
+    peer: *c_void,
+    major: c_int,
+    minor: c_int,
+    reference: c_int,
+    ref_to: c_int,
+    response: c_int,
+    data: *c_void,
+    size: c_int
+}
+
+
+#[link(name = "ecore_ipc")]
+extern "C" {
+    static ECORE_IPC_EVENT_CLIENT_ADD: c_int;
+    static ECORE_IPC_EVENT_CLIENT_DEL: c_int;
+    static ECORE_IPC_EVENT_CLIENT_DATA: c_int;
+    static ECORE_IPC_EVENT_SERVER_ADD: c_int;
+    static ECORE_IPC_EVENT_SERVER_DEL: c_int;
+    static ECORE_IPC_EVENT_SERVER_DATA: c_int;
+    fn ecore_ipc_init() -> c_int;
+    fn ecore_ipc_shutdown() -> c_int;
+    fn ecore_ipc_server_add(ctype: c_uint, name: *c_char, port: c_int,
+                            data: *c_void) -> *EcoreIpcServer;
+    fn ecore_ipc_server_connect(ctype: c_uint, name: *c_char, port: c_int,
+                                data: *c_void) -> *EcoreIpcServer;
+    fn ecore_ipc_server_del(svr: *EcoreIpcServer) -> *c_void;
+    fn ecore_ipc_server_connected_get(svr: *EcoreIpcServer) -> eina::EinaBool;
+    fn ecore_ipc_server_send(svr: *EcoreIpcServer, major: c_int, minor: c_int,
+                             reference: c_int, ref_to: c_int, response: c_int,
+                             data: *c_void, size: c_int) -> c_int;
+    fn ecore_ipc_server_flush(svr: *EcoreIpcServer);
+    fn ecore_ipc_client_send(cl: *EcoreIpcClient, major: c_int, minor: c_int,
+                             reference: c_int, ref_to: c_int, response: c_int,
+                             data: *c_void, size: c_int) -> c_int;
+    fn ecore_ipc_client_del(cl: *EcoreIpcClient) -> *c_void;
+    fn ecore_ipc_client_server_get(cl: *EcoreIpcClient) -> *EcoreIpcServer;
+    fn ecore_ipc_client_flush(cl: *EcoreIpcClient);
+}
+
+
+impl Message {
+    /// Create a message with the given opcodes and payload.
+    /// The reference numbers and response flag are left to zero.
+    pub fn new(major: int, minor: int, data: &[u8]) -> Message {
+        Message {
+            major: major, minor: minor,
+            reference: 0, ref_to: 0, response: 0,
+            data: Vec::from_slice(data)
+        }
+    }
+
+    /// Create a response message to 'self', with the same opcodes.
+    pub fn reply(&self, data: &[u8]) -> Message {
+        Message {
+            major: self.major, minor: self.minor,
+            reference: 0, ref_to: self.reference, response: 0,
+            data: Vec::from_slice(data)
+        }
+    }
+}
+
+impl Server {
+    /// Whether the given handle refers to the same server.
+    pub fn is(&self, other: &Server) -> bool {
+        self._eo == other._eo
+    }
+
+    /// Whether the server could be created or connected to.
+    pub fn is_valid(&self) -> bool {
+        self._eo.is_not_null()
+    }
+
+    /// Close the connection and free the server.
+    pub fn del(self) {
+        unsafe { ecore_ipc_server_del(self._eo); }
+    }
+
+    /// Whether the connection to the server is established.
+    pub fn connected_get(&self) -> bool {
+        unsafe { from_eina_to_bool(ecore_ipc_server_connected_get(self._eo)) }
+    }
+
+    /// Send a message to the server.
+    /// Returns the number of bytes queued for sending.
+    pub fn send(&self, msg: &Message) -> int {
+        unsafe {
+            ecore_ipc_server_send(self._eo, msg.major as c_int, msg.minor as c_int,
+                                  msg.reference as c_int, msg.ref_to as c_int,
+                                  msg.response as c_int,
+                                  _data_ptr(msg), msg.data.len() as c_int) as int
+        }
+    }
+
+    /// Flush all pending data to the server.
+    pub fn flush(&self) {
+        unsafe { ecore_ipc_server_flush(self._eo) }
+    }
+}
+
+impl Client {
+    /// Whether the given handle refers to the same client.
+    pub fn is(&self, other: &Client) -> bool {
+        self._eo == other._eo
+    }
+
+    /// Close the connection and free the client.
+    pub fn del(self) {
+        unsafe { ecore_ipc_client_del(self._eo); }
+    }
+
+    /// Send a message to the client.
+    /// Returns the number of bytes queued for sending.
+    pub fn send(&self, msg: &Message) -> int {
+        unsafe {
+            ecore_ipc_client_send(self._eo, msg.major as c_int, msg.minor as c_int,
+                                  msg.reference as c_int, msg.ref_to as c_int,
+                                  msg.response as c_int,
+                                  _data_ptr(msg), msg.data.len() as c_int) as int
+        }
+    }
+
+    /// Get the server the client is connected to.
+    pub fn server_get(&self) -> Server {
+        Server { _eo: unsafe { ecore_ipc_client_server_get(self._eo) } }
+    }
+
+    /// Flush all pending data to the client.
+    pub fn flush(&self) {
+        unsafe { ecore_ipc_client_flush(self._eo) }
+    }
+}
+
+/// Initialize the Ecore_Ipc library.
+pub fn init() -> int {
+    unsafe { ecore_ipc_init() as int }
+}
+
+/// Shut down the Ecore_Ipc library.
+pub fn shutdown() -> int {
+    unsafe { ecore_ipc_shutdown() as int }
+}
+
+/// Create an IPC server to listen for connections.
+pub fn server_add(ctype: IpcType, name: &str, port: int) -> Server {
+    name.with_c_str(|c_name| unsafe {
+        Server { _eo: ecore_ipc_server_add(ctype, c_name, port as c_int, ptr::null()) }
+    })
+}
+
+/// Create a connection to the specified IPC server.
+pub fn server_connect(ctype: IpcType, name: &str, port: int) -> Server {
+    name.with_c_str(|c_name| unsafe {
+        Server { _eo: ecore_ipc_server_connect(ctype, c_name, port as c_int, ptr::null()) }
+    })
+}
+
+/// Add a handler called when a client connected to a server.
+pub fn event_client_add_handler_add(func: IpcClientClosure) -> ecore::EventHandler {
+    _client_handler_add(unsafe { ECORE_IPC_EVENT_CLIENT_ADD }, func)
+}
+
+/// Add a handler called when a client disconnected from a server.
+pub fn event_client_del_handler_add(func: IpcClientClosure) -> ecore::EventHandler {
+    _client_handler_add(unsafe { ECORE_IPC_EVENT_CLIENT_DEL }, func)
+}
+
+/// Add a handler for the messages sent by clients to a server.
+pub fn event_client_data_handler_add(func: IpcClientDataClosure) -> ecore::EventHandler {
+    let mut func = func;
+    ecore::_event_handler_closure_add(unsafe { ECORE_IPC_EVENT_CLIENT_DATA },
+                                      box move |&mut: _htype: int, event: &EventInfo| {
+        let c_ev: &_CIpcEventData = unsafe { transmute(event) };
+        let client = Client { _eo: unsafe { transmute(c_ev.peer) } };
+        func.call_mut((&client, &_message_from_c(c_ev)))
+    })
+}
+
+/// Add a handler called when a connection to a server is established.
+pub fn event_server_add_handler_add(func: IpcServerClosure) -> ecore::EventHandler {
+    _server_handler_add(unsafe { ECORE_IPC_EVENT_SERVER_ADD }, func)
+}
+
+/// Add a handler called when a connection to a server is lost.
+pub fn event_server_del_handler_add(func: IpcServerClosure) -> ecore::EventHandler {
+    _server_handler_add(unsafe { ECORE_IPC_EVENT_SERVER_DEL }, func)
+}
+
+/// Add a handler for the messages received from a server.
+pub fn event_server_data_handler_add(func: IpcServerDataClosure) -> ecore::EventHandler {
+    let mut func = func;
+    ecore::_event_handler_closure_add(unsafe { ECORE_IPC_EVENT_SERVER_DATA },
+                                      box move |&mut: _htype: int, event: &EventInfo| {
+        let c_ev: &_CIpcEventData = unsafe { transmute(event) };
+        let server = Server { _eo: unsafe { transmute(c_ev.peer) } };
+        func.call_mut((&server, &_message_from_c(c_ev)))
+    })
+}
+
+fn _client_handler_add(htype: c_int, func: IpcClientClosure) -> ecore::EventHandler {
+    let mut func = func;
+    ecore::_event_handler_closure_add(htype,
+                                      box move |&mut: _htype: int, event: &EventInfo| {
+        // The add and del events only hold the client pointer.
+        let client: &*EcoreIpcClient = unsafe { transmute(event) };
+        func.call_mut((&Client { _eo: *client },))
+    })
+}
+
+fn _server_handler_add(htype: c_int, func: IpcServerClosure) -> ecore::EventHandler {
+    let mut func = func;
+    ecore::_event_handler_closure_add(htype,
+                                      box move |&mut: _htype: int, event: &EventInfo| {
+        // The add and del events only hold the server pointer.
+        let server: &*EcoreIpcServer = unsafe { transmute(event) };
+        func.call_mut((&Server { _eo: *server },))
+    })
+}
+
+/* Copy a received message and its payload into an owned value */
+fn _message_from_c(c_ev: &_CIpcEventData) -> Message {
+    Message {
+        major: c_ev.major as int,
+        minor: c_ev.minor as int,
+        reference: c_ev.reference as int,
+        ref_to: c_ev.ref_to as int,
+        response: c_ev.response as int,
+        data: if c_ev.data.is_null() { Vec::new() } else {
+            unsafe { raw::from_buf(transmute(c_ev.data), c_ev.size as uint) }
+        }
+    }
+}
+
+fn _data_ptr(msg: &Message) -> *c_void {
+    if msg.data.is_empty() { ptr::null() }
+    else { unsafe { transmute(msg.data.as_ptr()) } }
+}
@@ -0,0 +1,1282 @@
+// Ecore Rust bindings for EFL.
+// Copyright (C) 2014  Luis Araujo <araujoc.luisf@gmail.com>
+
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 2.1 of the License, or (at your option) any later version.
+
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+
+
+extern crate libc;
+
+use std::ptr;
+use std::vec::raw;
+use std::option::Option;
+use std::mem::transmute;
+
+use ecore::libc::{c_int, c_uint, c_char, c_void};
+use eseful::{to_c_args, from_bool_to_eina, from_eina_to_bool, from_c_to_string, EventInfo};
+use eo;
+use eina;
+use evas;
+
+pub mod exe;
+pub mod thread;
+pub mod future;
+pub mod con;
+pub mod ipc;
+pub mod file;
+pub mod getopt;
+
+pub enum EcoreEvas {}
+
+pub enum EcoreEventHandler {}
+
+pub enum EcoreFdHandler {}
+
+/// A raw file descriptor watched by an FdHandler.
+pub type RawFd = c_int;
+
+/// Flags describing which conditions of a file descriptor are watched.
+pub type FdHandlerFlags = c_uint;
+
+/// Fd Read mask.
+pub static ECORE_FD_READ: FdHandlerFlags = 1;
+/// Fd Write mask.
+pub static ECORE_FD_WRITE: FdHandlerFlags = 2;
+/// Fd Error mask.
+pub static ECORE_FD_ERROR: FdHandlerFlags = 4;
+
+pub enum EcoreEvent {
+    EcoreEventNone,
+    EcoreEventSignalUser,
+    EcoreEventSignalHup,
+    EcoreEventSignalExit,
+    EcoreEventSignalPower,
+    EcoreEventSignalRealtime,
+    EcoreEventMemoryState,
+    EcoreEventPowerState,
+    EcoreEventLocaleChanged,
+    EcoreEventHostnameChanged,
+    EcoreEventSystemTimedateChanged,
+    EcoreEventCount
+}
+
+pub struct SigInfo;
+
+pub struct EcoreEventSignalExit {
+    pub interrupt: eina::EinaBool,
+    pub quit: eina::EinaBool,
+    pub terminate: eina::EinaBool,
+    pub ext_data: *c_void,
+    pub data: SigInfo
+}
+
+pub struct EcoreEventSignalUser {
+    /// The signal number. Either 1 or 2.
+    pub number: int,
+    pub ext_data: *c_void,
+    pub data: SigInfo
+}
+
+/* C representation of the signal events */
+struct _CEcoreEventSignalExit {
+    /* 'interrupt', 'quit' and 'terminate' bit fields */
+    _bits: eina::EinaBool,
+    ext_data: *c_void
+}
+
+struct _CEcoreEventSignalUser {
+    number: c_int,
+    ext_data: *c_void
+}
+
+/// A custom event type posting values of type 'T' through the main loop.
+pub struct EventType<T> {
+    _id: c_int
+}
+
+type EcoreTimer = eo::Eo;
+type EcoreIdler = eo::Eo;
+type EcoreIdleEnterer = eo::Eo;
+type EcoreIdleExiter = eo::Eo;
+type EcoreAnimator = eo::Eo;
+
+pub static ECORE_CALLBACK_CANCEL: eina::EinaBool = eina::EINA_FALSE;
+pub static ECORE_CALLBACK_RENEW: eina::EinaBool = eina::EINA_TRUE;
+
+/* High level callback notation */
+pub type EcoreTaskCb<T> = fn (&Option<T>) -> eina::EinaBool;
+/* C level callback notation */
+type CEcoreTaskCb = fn (*c_void) -> u8;
+type CEcoreTimelineCb = fn (*c_void, f64) -> u8;
+type CEcoreFdCb = fn (*c_void, *EcoreFdHandler) -> u8;
+type CEcoreCb = fn (*c_void);
+type CEcoreDataCb = fn (*c_void) -> *c_void;
+type CEcoreEndCb = fn (*c_void, *c_void);
+
+/* High level callback notation */
+pub type EcoreEventHandlerCb<T> = fn (&Option<T>, int, &EventInfo) -> bool;
+/* C level callback notation */
+type CEcoreEventHandlerCb = fn (*c_void, c_int, *c_void) -> u8;
+
+/* Closure callback notation */
+pub type EcoreTaskClosure = Box<FnMut() -> bool + 'static>;
+pub type EcoreEventHandlerClosure = Box<FnMut(int, &EventInfo) -> bool + 'static>;
+pub type EcoreTypedEventClosure<T> = Box<FnMut(&T) -> bool + 'static>;
+
+pub type EcoreTimelineClosure = Box<FnMut(f64) -> bool + 'static>;
+/* The closure receives the watched fd and the conditions that are active */
+pub type EcoreFdClosure = Box<FnMut(RawFd, FdHandlerFlags) -> bool + 'static>;
+
+/// Interpolation maps for 'animator_pos_map'.
+pub enum EcorePosMap {
+    /// Linear 0.0 -> 1.0
+    EcorePosMapLinear,
+    /// Start slow then speed up
+    EcorePosMapAccelerate,
+    /// Start fast then slow down
+    EcorePosMapDecelerate,
+    /// Start slow, speed up then slow down at end
+    EcorePosMapSinusoidal,
+    /// Start slow then speed up, v1 being a power factor
+    EcorePosMapAccelerateFactor,
+    /// Start fast then slow down, v1 being a power factor
+    EcorePosMapDecelerateFactor,
+    /// Start slow, speed up then slow down at end, v1 being a power factor
+    EcorePosMapSinusoidalFactor,
+    /// Start at gradient * v1, interpolated via power of v2 curve
+    EcorePosMapDivisorInterp,
+    /// Start at 0.0 then "drop" like a ball bouncing to the ground at 1.0,
+    /// and bounce v2 times, with decay factor of v1
+    EcorePosMapBounce,
+    /// Start at 0.0 then "wobble" like a spring rest position 1.0,
+    /// and wobble v2 times, with decay factor of v1
+    EcorePosMapSpring
+}
+
+/* Heap state shared by a task handle and its C callback:
+ * the ecore object of type 'E' and the closure 'F' */
+struct _TaskState<E, F> {
+    _eo: *E,
+    func: F
+}
+
+/* Define an owned handle around a task state.
+ * Dropping the handle deletes the ecore object (if it is still alive)
+ * and frees the callback state. */
+macro_rules! task_handle(
+    ($(#[$attr:meta])* $name:ident, $obj:ty, $func:ty, $del:ident) => (
+        $(#[$attr])*
+        #[must_use]
+        pub struct $name {
+            _state: *mut _TaskState<$obj, $func>
+        }
+
+        impl $name {
+            #[allow(dead_code)]
+            fn _eo(&self) -> *$obj {
+                unsafe { (*self._state)._eo }
+            }
+
+            /// Delete the object. This is the same as dropping the handle.
+            pub fn del(self) {}
+
+            /// Whether the object is still scheduled by ecore.
+            pub fn is_alive(&self) -> bool {
+                self._eo().is_not_null()
+            }
+        }
+
+        #[unsafe_destructor]
+        impl Drop for $name {
+            fn drop(&mut self) {
+                unsafe {
+                    let state: Box<_TaskState<$obj, $func>> = transmute(self._state);
+                    if state._eo.is_not_null() { $del(state._eo); }
+                }
+            }
+        }
+    )
+)
+
+task_handle!(
+    /// Owned handle of an ecore timer.
+    /// Dropping the handle deletes the timer and frees its callback state.
+    Timer, eo::Eo, EcoreTaskClosure, ecore_timer_del)
+
+task_handle!(
+    /// Owned handle of an idler, called whenever the main loop is idle.
+    Idler, eo::Eo, EcoreTaskClosure, ecore_idler_del)
+
+task_handle!(
+    /// Owned handle of an idle enterer, called when the main loop enters
+    /// the idle state.
+    IdleEnterer, eo::Eo, EcoreTaskClosure, ecore_idle_enterer_del)
+
+task_handle!(
+    /// Owned handle of an idle exiter, called when the main loop wakes up
+    /// from the idle state.
+    IdleExiter, eo::Eo, EcoreTaskClosure, ecore_idle_exiter_del)
+
+task_handle!(
+    /// Owned handle of an animator, called once per animation frame.
+    Animator, eo::Eo, EcoreTaskClosure, ecore_animator_del)
+
+task_handle!(
+    /// Owned handle of a timeline animator, called once per animation
+    /// frame with the position (0.0 to 1.0) within its runtime.
+    AnimatorTimeline, eo::Eo, EcoreTimelineClosure, ecore_animator_del)
+
+task_handle!(
+    /// Owned handle of a file descriptor handler on the main loop.
+    /// Dropping the handle deletes the handler and frees its callback state.
+    FdHandler, EcoreFdHandler, EcoreFdClosure, ecore_main_fd_handler_del)
+
+impl Timer {
+    /// Pause the timer.
+    pub fn freeze(&self) {
+        unsafe { ecore_timer_freeze(self._eo()) }
+    }
+
+    /// Resume a frozen timer.
+    pub fn thaw(&self) {
+        unsafe { ecore_timer_thaw(self._eo()) }
+    }
+
+    /// Change the interval the timer ticks of.
+    pub fn interval_set(&self, inv: f64) {
+        unsafe { ecore_timer_interval_set(self._eo(), inv) }
+    }
+
+    /// Get the interval the timer ticks on.
+    pub fn interval_get(&self) -> f64 {
+        unsafe { ecore_timer_interval_get(self._eo()) }
+    }
+
+    /// Add some delay for the next occurrence of the timer.
+    pub fn delay(&self, add: f64) {
+        unsafe { ecore_timer_delay(self._eo(), add) }
+    }
+
+    /// Reset the timer to its initial interval.
+    pub fn reset(&self) {
+        unsafe { ecore_timer_reset(self._eo()) }
+    }
+
+    /// Get the pending time regarding the timer.
+    pub fn pending_get(&self) -> f64 {
+        unsafe { ecore_timer_pending_get(self._eo()) }
+    }
+
+    /// Set the precision to be used by all timers.
+    pub fn precision_set(value: f64) {
+        unsafe { ecore_timer_precision_set(value) }
+    }
+
+    /// Retrieve the current precision used by timer infrastructure.
+    pub fn precision_get() -> f64 {
+        unsafe { ecore_timer_precision_get() }
+    }
+}
+
+impl Animator {
+    /// Suspend the animator.
+    pub fn freeze(&self) {
+        unsafe { ecore_animator_freeze(self._eo()) }
+    }
+
+    /// Restore execution of a frozen animator.
+    pub fn thaw(&self) {
+        unsafe { ecore_animator_thaw(self._eo()) }
+    }
+}
+
+impl AnimatorTimeline {
+    /// Suspend the animator.
+    pub fn freeze(&self) {
+        unsafe { ecore_animator_freeze(self._eo()) }
+    }
+
+    /// Restore execution of a frozen animator.
+    pub fn thaw(&self) {
+        unsafe { ecore_animator_thaw(self._eo()) }
+    }
+}
+
+impl FdHandler {
+    /// Retrieve the file descriptor that the handler is watching.
+    pub fn fd_get(&self) -> RawFd {
+        unsafe { ecore_main_fd_handler_fd_get(self._eo()) }
+    }
+
+    /// Get which flags are active on the handler.
+    pub fn active_get(&self, flags: FdHandlerFlags) -> bool {
+        unsafe {
+            from_eina_to_bool(ecore_main_fd_handler_active_get(self._eo(), flags))
+        }
+    }
+
+    /// Set what active streams the handler should be monitoring.
+    pub fn active_set(&self, flags: FdHandlerFlags) {
+        unsafe { ecore_main_fd_handler_active_set(self._eo(), flags) }
+    }
+}
+
+/// Owned handle of an event handler whose callback is a closure.
+/// Dropping the handle deletes the handler and frees its closure state.
+#[must_use]
+pub struct EventHandler {
+    _eo: *EcoreEventHandler,
+    _cb: *mut EcoreEventHandlerClosure
+}
+
+impl EventHandler {
+    /// Delete the event handler. This is the same as dropping the handle.
+    pub fn del(self) {}
+}
+
+impl Drop for EventHandler {
+    fn drop(&mut self) {
+        unsafe {
+            if self._eo.is_not_null() { ecore_event_handler_del(self._eo); }
+            drop(transmute::<*mut EcoreEventHandlerClosure,
+                             Box<EcoreEventHandlerClosure>>(self._cb))
+        }
+    }
+}
+
+pub type EcoreEvasEventCb = fn (&EcoreEvas);
+type _CEcoreEvasEventCb = fn (*EcoreEvas);
+
+/* Closure called for the events of an EcoreEvasWindow */
+pub type EcoreEvasWindowClosure = Box<FnMut(&EcoreEvasWindow) + 'static>;
+
+/* Events of a window, used as index of its closures */
+enum _WindowCb {
+    _WindowCbDeleteRequest,
+    _WindowCbMove,
+    _WindowCbResize,
+    _WindowCbShow,
+    _WindowCbHide,
+    _WindowCbFocusIn,
+    _WindowCbFocusOut,
+    _WindowCbPreRender,
+    _WindowCbPostRender,
+    _WindowCbLast
+}
+
+/* Heap state of a window, found back from its callbacks
+ * through the Ecore_Evas data */
+struct _WindowState {
+    window: *EcoreEvasWindow,
+    callbacks: Vec<Option<EcoreEvasWindowClosure>>
+}
+
+/* Key used to store the window state as Ecore_Evas data */
+static _WINDOW_KEY: &'static str = "_rust_efl_window";
+
+/// Owned Ecore_Evas window.
+/// Dropping the window frees the Ecore_Evas and its callbacks.
+pub struct EcoreEvasWindow {
+    _ee: *EcoreEvas,
+    _state: *mut _WindowState
+}
+
+#[link(name = "ecore")]
+extern "C" {
+    fn ecore_init() -> c_int;
+    fn ecore_app_args_set(argc: c_int, argv: **c_char);
+    fn ecore_app_args_get(argc: *mut c_int, argv: *mut **c_char);
+    fn ecore_main_loop_begin();
+    fn ecore_main_loop_quit();
+    fn ecore_main_loop_thread_safe_call_async(callback: CEcoreCb, data: *c_void);
+    fn ecore_main_loop_thread_safe_call_sync(callback: CEcoreDataCb,
+                                             data: *c_void) -> *c_void;
+    fn ecore_time_get() -> f64;
+    fn ecore_time_unix_get() -> f64;
+    fn ecore_loop_time_get() -> f64;
+    fn ecore_shutdown() -> c_int;
+    fn ecore_timer_add(inv: f64, func: CEcoreTaskCb, data: *c_void) -> *EcoreTimer;
+    fn ecore_timer_del(timer: *EcoreTimer) -> *c_void;
+    fn ecore_timer_freeze(timer: *EcoreTimer);
+    fn ecore_timer_thaw(timer: *EcoreTimer);
+    fn ecore_timer_interval_set(timer: *EcoreTimer, inv: f64);
+    fn ecore_timer_interval_get(timer: *EcoreTimer) -> f64;
+    fn ecore_timer_delay(timer: *EcoreTimer, add: f64);
+    fn ecore_timer_reset(timer: *EcoreTimer);
+    fn ecore_timer_pending_get(timer: *EcoreTimer) -> f64;
+    fn ecore_timer_precision_set(value: f64);
+    fn ecore_timer_precision_get() -> f64;
+    fn ecore_idler_add(func: CEcoreTaskCb, data: *c_void) -> *EcoreIdler;
+    fn ecore_idler_del(idler: *EcoreIdler) -> *c_void;
+    fn ecore_idle_enterer_add(func: CEcoreTaskCb, data: *c_void) -> *EcoreIdleEnterer;
+    fn ecore_idle_enterer_before_add(func: CEcoreTaskCb,
+                                     data: *c_void) -> *EcoreIdleEnterer;
+    fn ecore_idle_enterer_del(idle_enterer: *EcoreIdleEnterer) -> *c_void;
+    fn ecore_idle_exiter_add(func: CEcoreTaskCb, data: *c_void) -> *EcoreIdleExiter;
+    fn ecore_idle_exiter_del(idle_exiter: *EcoreIdleExiter) -> *c_void;
+    fn ecore_animator_add(func: CEcoreTaskCb, data: *c_void) -> *EcoreAnimator;
+    fn ecore_animator_timeline_add(runtime: f64, func: CEcoreTimelineCb,
+                                   data: *c_void) -> *EcoreAnimator;
+    fn ecore_animator_del(animator: *EcoreAnimator) -> *c_void;
+    fn ecore_animator_freeze(animator: *EcoreAnimator);
+    fn ecore_animator_thaw(animator: *EcoreAnimator);
+    fn ecore_animator_frametime_set(frametime: f64);
+    fn ecore_animator_frametime_get() -> f64;
+    fn ecore_animator_pos_map(pos: f64, map: c_uint, v1: f64, v2: f64) -> f64;
+    fn ecore_event_handler_add(htype: c_int, func: CEcoreEventHandlerCb, 
+                               data: *c_void) -> *EcoreEventHandler;
+    fn ecore_event_handler_del(event_handler: *EcoreEventHandler) -> *c_void;
+    fn ecore_event_type_new() -> c_int;
+    fn ecore_event_add(htype: c_int, ev: *c_void,
+                       func_free: CEcoreEndCb, data: *c_void) -> *c_void;
+    fn ecore_main_fd_handler_add(fd: c_int, flags: c_uint, func: CEcoreFdCb,
+                                 data: *c_void, buf_func: CEcoreFdCb,
+                                 buf_data: *c_void) -> *EcoreFdHandler;
+    fn ecore_main_fd_handler_del(fd_handler: *EcoreFdHandler) -> *c_void;
+    fn ecore_main_fd_handler_fd_get(fd_handler: *EcoreFdHandler) -> c_int;
+    fn ecore_main_fd_handler_active_get(fd_handler: *EcoreFdHandler,
+                                        flags: c_uint) -> eina::EinaBool;
+    fn ecore_main_fd_handler_active_set(fd_handler: *EcoreFdHandler, flags: c_uint);
+}
+
+#[link(name = "ecore_evas")]
+extern "C" {
+    fn ecore_evas_init() -> c_int;
+    fn ecore_evas_shutdown() -> c_int;
+    fn ecore_evas_new(engine_name: *c_char, 
+                      x: c_int, y: c_int, 
+                      w: c_int, h: c_int,
+                      extra_options: *c_char) -> *EcoreEvas;
+    fn ecore_evas_show(ee: *EcoreEvas);
+    fn ecore_evas_get(ee: *EcoreEvas) -> *evas::Evas;
+    fn ecore_evas_data_set(ee: *EcoreEvas, key: *c_char, data: *c_void);
+    fn ecore_evas_data_get(ee: *EcoreEvas, key: *c_char) -> *c_void;
+    fn ecore_evas_free(ee: *EcoreEvas);
+    fn ecore_evas_callback_resize_set(ee: *EcoreEvas, func: _CEcoreEvasEventCb);
+    fn ecore_evas_callback_move_set(ee: *EcoreEvas, func: _CEcoreEvasEventCb);
+    fn ecore_evas_callback_show_set(ee: *EcoreEvas, func: _CEcoreEvasEventCb);
+    fn ecore_evas_callback_hide_set(ee: *EcoreEvas, func: _CEcoreEvasEventCb);
+    fn ecore_evas_callback_delete_request_set(ee: *EcoreEvas, func: _CEcoreEvasEventCb);
+    fn ecore_evas_callback_focus_in_set(ee: *EcoreEvas, func: _CEcoreEvasEventCb);
+    fn ecore_evas_callback_focus_out_set(ee: *EcoreEvas, func: _CEcoreEvasEventCb);
+    fn ecore_evas_callback_pre_render_set(ee: *EcoreEvas, func: _CEcoreEvasEventCb);
+    fn ecore_evas_callback_post_render_set(ee: *EcoreEvas, func: _CEcoreEvasEventCb);
+    fn ecore_evas_hide(ee: *EcoreEvas);
+    fn ecore_evas_move(ee: *EcoreEvas, x: c_int, y: c_int);
+    fn ecore_evas_resize(ee: *EcoreEvas, w: c_int, h: c_int);
+    fn ecore_evas_move_resize(ee: *EcoreEvas, x: c_int, y: c_int, w: c_int, h: c_int);
+    fn ecore_evas_visibility_get(ee: *EcoreEvas) -> c_int;
+    fn ecore_evas_title_set(ee: *EcoreEvas, t: *c_char);
+    fn ecore_evas_title_get(ee: *EcoreEvas) -> *c_char;
+    fn ecore_evas_fullscreen_set(ee: *EcoreEvas, on: eina::EinaBool);
+    fn ecore_evas_fullscreen_get(ee: *EcoreEvas) -> eina::EinaBool;
+    fn ecore_evas_maximized_set(ee: *EcoreEvas, on: eina::EinaBool);
+    fn ecore_evas_maximized_get(ee: *EcoreEvas) -> eina::EinaBool;
+    fn ecore_evas_iconified_set(ee: *EcoreEvas, on: eina::EinaBool);
+    fn ecore_evas_iconified_get(ee: *EcoreEvas) -> eina::EinaBool;
+    fn ecore_evas_borderless_set(ee: *EcoreEvas, on: eina::EinaBool);
+    fn ecore_evas_borderless_get(ee: *EcoreEvas) -> eina::EinaBool;
+    fn ecore_evas_alpha_set(ee: *EcoreEvas, alpha: eina::EinaBool);
+    fn ecore_evas_alpha_get(ee: *EcoreEvas) -> eina::EinaBool;
+    fn ecore_evas_rotation_set(ee: *EcoreEvas, rot: c_int);
+    fn ecore_evas_rotation_get(ee: *EcoreEvas) -> c_int;
+    fn ecore_evas_buffer_new(w: c_int, h: c_int) -> *EcoreEvas;
+    fn ecore_evas_buffer_pixels_get(ee: *EcoreEvas) -> *c_void;
+    fn ecore_evas_manual_render_set(ee: *EcoreEvas, manual_render: eina::EinaBool);
+    fn ecore_evas_manual_render_get(ee: *EcoreEvas) -> eina::EinaBool;
+    fn ecore_evas_manual_render(ee: *EcoreEvas);
+    fn ecore_evas_geometry_get(ee: *EcoreEvas,
+                               x: *c_int, y: *c_int,
+                               w: *c_int, h: *c_int);
+}
+
+pub fn event_handler_add<T>(htype: EcoreEvent, 
+                            func: EcoreEventHandlerCb<T>, 
+                            data: &Option<T>) -> Box<EcoreEventHandler> {
+    unsafe { 
+        transmute(ecore_event_handler_add(htype as c_int, transmute(func), transmute(data)))
+    }
+}
+
+impl<T: 'static> EventType<T> {
+    /// Allocate a new event type identifier.
+    pub fn new() -> EventType<T> {
+        EventType { _id: unsafe { ecore_event_type_new() } }
+    }
+
+    /// The raw event type identifier.
+    pub fn id(&self) -> int {
+        self._id as int
+    }
+
+    /// Post an event carrying 'value'.
+    /// The value is dropped once all the handlers have been called.
+    pub fn add(&self, value: T) -> bool {
+        unsafe {
+            let c_ev: *c_void = transmute(box value);
+            let c_free: CEcoreEndCb = transmute(_event_free_cb::<T>);
+            let added = ecore_event_add(self._id, c_ev, c_free, ptr::null()).is_not_null();
+            if !added { drop(transmute::<*c_void, Box<T>>(c_ev)) }
+            added
+        }
+    }
+
+    /// Add a handler receiving the values posted with this event type.
+    pub fn handler_add(&self, func: EcoreTypedEventClosure<T>) -> EventHandler {
+        let mut func = func;
+        _event_handler_closure_add(self._id,
+                                   box move |&mut: _htype: int, event: &EventInfo| {
+            let ev: &T = unsafe { transmute(event) };
+            func.call_mut((ev,))
+        })
+    }
+}
+
+/* Drop the value of a custom event */
+extern "C" fn _event_free_cb<T>(_data: *c_void, ev: *c_void) {
+    drop(unsafe { transmute::<*c_void, Box<T>>(ev) })
+}
+
+/// Add a handler for the exit signal events, receiving the typed event.
+pub fn event_signal_exit_handler_add(
+    func: EcoreTypedEventClosure<EcoreEventSignalExit>) -> EventHandler {
+    let mut func = func;
+    event_handler_add_closure(EcoreEventSignalExit,
+                              box move |&mut: _htype: int, event: &EventInfo| {
+        let c_ev: &_CEcoreEventSignalExit = unsafe { transmute(event) };
+        let ev = EcoreEventSignalExit {
+            interrupt: c_ev._bits & 1,
+            quit: (c_ev._bits >> 1) & 1,
+            terminate: (c_ev._bits >> 2) & 1,
+            ext_data: c_ev.ext_data,
+            data: SigInfo
+        };
+        func.call_mut((&ev,))
+    })
+}
+
+/// Add a handler for the user signal (SIGUSR1/SIGUSR2) events.
+pub fn event_signal_user_handler_add(
+    func: EcoreTypedEventClosure<EcoreEventSignalUser>) -> EventHandler {
+    let mut func = func;
+    event_handler_add_closure(EcoreEventSignalUser,
+                              box move |&mut: _htype: int, event: &EventInfo| {
+        let c_ev: &_CEcoreEventSignalUser = unsafe { transmute(event) };
+        let ev = EcoreEventSignalUser {
+            number: c_ev.number as int,
+            ext_data: c_ev.ext_data,
+            data: SigInfo
+        };
+        func.call_mut((&ev,))
+    })
+}
+
+/// Add a handler called when the system locale changed.
+pub fn event_locale_changed_handler_add(func: EcoreTaskClosure) -> EventHandler {
+    let mut func = func;
+    event_handler_add_closure(EcoreEventLocaleChanged,
+                              box move |&mut: _htype: int, _event: &EventInfo| {
+        func.call_mut(())
+    })
+}
+
+/// Add a handler called when the system hostname changed.
+pub fn event_hostname_changed_handler_add(func: EcoreTaskClosure) -> EventHandler {
+    let mut func = func;
+    event_handler_add_closure(EcoreEventHostnameChanged,
+                              box move |&mut: _htype: int, _event: &EventInfo| {
+        func.call_mut(())
+    })
+}
+
+/// Add an event handler whose callback is a closure.
+/// The closure is moved to the heap and owned by the returned handle.
+pub fn event_handler_add_closure(htype: EcoreEvent,
+                                 func: EcoreEventHandlerClosure) -> EventHandler {
+    _event_handler_closure_add(htype as c_int, func)
+}
+
+/* Add a closure event handler for a raw event type identifier.
+ * Private items are visible to child modules, so exe, con and ipc use
+ * it for their event types that are only known at runtime */
+fn _event_handler_closure_add(htype: c_int,
+                              func: EcoreEventHandlerClosure) -> EventHandler {
+    unsafe {
+        let c_cb: *mut EcoreEventHandlerClosure = transmute(box func);
+        let c_func: CEcoreEventHandlerCb = transmute(_event_handler_closure_cb);
+        EventHandler {
+            _eo: ecore_event_handler_add(htype, c_func, transmute(c_cb)),
+            _cb: c_cb
+        }
+    }
+}
+
+/* Dispatch an event to the closure stored in 'data' */
+extern "C" fn _event_handler_closure_cb(data: *c_void, htype: c_int,
+                                        event: *c_void) -> u8 {
+    unsafe {
+        let func: &mut EcoreEventHandlerClosure = transmute(data);
+        from_bool_to_eina(func.call_mut((htype as int, transmute(event))))
+    }
+}
+
+pub fn init() -> i32 {
+    unsafe { ecore_init() as i32 }
+}
+
+pub fn app_args_set(argc: uint, argv: Vec<String>) {
+    let vchars_ptr: **c_char = to_c_args(argv);
+    unsafe { ecore_app_args_set(argc as c_int, vchars_ptr) }
+}
+
+/// Get the arguments previously set with 'app_args_set'.
+pub fn app_args_get() -> Vec<String> {
+    let mut argc: c_int = 0;
+    let mut argv: **c_char = ptr::null();
+    unsafe {
+        ecore_app_args_get(&mut argc, &mut argv);
+        range(0, argc as int).map(|i| {
+            from_c_to_string(*argv.offset(i)).unwrap_or(String::new())
+        }).collect()
+    }
+}
+
+pub fn main_loop_begin() {
+    unsafe { ecore_main_loop_begin() }
+}
+
+pub fn main_loop_quit() {
+    unsafe { ecore_main_loop_quit() }
+}
+
+/// Call 'func' asynchronously in the main loop.
+/// This can be used from any thread to hand work over to the main loop,
+/// for example to update widgets once a background job is done.
+pub fn main_loop_thread_safe_call_async(func: proc():Send) {
+    unsafe {
+        let c_data: *c_void = transmute(box func);
+        ecore_main_loop_thread_safe_call_async(transmute(_thread_safe_cb), c_data)
+    }
+}
+
+/// Call 'func' in the main loop and wait for its result.
+/// If called from the main loop itself, 'func' is run right away.
+pub fn main_loop_thread_safe_call_sync<R: Send>(func: proc():Send -> R) -> R {
+    let mut result: Option<R> = None;
+    // The calling thread is blocked until 'func' is done, so the result
+    // slot outlives the call.
+    let slot: uint = unsafe { transmute(&mut result) };
+    let call: proc():Send = proc() {
+        let r: &mut Option<R> = unsafe { transmute(slot) };
+        *r = Some(func());
+    };
+    unsafe {
+        let c_data: *c_void = transmute(box call);
+        ecore_main_loop_thread_safe_call_sync(transmute(_thread_safe_sync_cb), c_data);
+    }
+    result.unwrap()
+}
+
+/* Run and free the proc stored in 'data' */
+extern "C" fn _thread_safe_cb(data: *c_void) {
+    let func: Box<proc():Send> = unsafe { transmute(data) };
+    (*func)()
+}
+
+extern "C" fn _thread_safe_sync_cb(data: *c_void) -> *c_void {
+    _thread_safe_cb(data);
+    ptr::null()
+}
+
+pub fn shutdown() -> int {
+    unsafe { ecore_shutdown() as int }
+}
+
+pub fn time_get() -> f64 {
+    unsafe { ecore_time_get() }
+}
+
+pub fn time_unix_get() -> f64 {
+    unsafe { ecore_time_unix_get() }
+}
+
+/// Get the time, in seconds, at which the current main loop
+/// iteration started.
+pub fn loop_time_get() -> f64 {
+    unsafe { ecore_loop_time_get() }
+}
+
+pub fn timer_add<T>(inv: f64, func: EcoreTaskCb<T>, data: &Option<T>) {
+    let c_data: *c_void = unsafe { transmute(data) };
+    let c_func: CEcoreTaskCb = unsafe { transmute(func) };
+    unsafe { ecore_timer_add(inv, c_func, c_data); }
+}
+
+/// Add a timer to call the given function every 'inv' seconds.
+/// The timer owns 'data' and runs until the function returns
+/// ECORE_CALLBACK_CANCEL or the returned handle is dropped.
+pub fn timer_new<T: 'static>(inv: f64, func: EcoreTaskCb<T>, data: Option<T>) -> Timer {
+    timer_add_closure(inv, box move |&mut:| from_eina_to_bool(func(&data)))
+}
+
+/// Add a timer whose callback is a closure.
+/// The closure keeps running every 'inv' seconds until it returns false
+/// or the returned handle is dropped.
+pub fn timer_add_closure(inv: f64, func: EcoreTaskClosure) -> Timer {
+    Timer {
+        _state: _task_state_new(func, |c_data| unsafe {
+            ecore_timer_add(inv, transmute(_task_cb), c_data)
+        })
+    }
+}
+
+/// Add an idler handler.
+/// The closure is called repeatedly while the main loop is idle,
+/// until it returns false or the returned handle is dropped.
+pub fn idler_add(func: EcoreTaskClosure) -> Idler {
+    Idler {
+        _state: _task_state_new(func, |c_data| unsafe {
+            ecore_idler_add(transmute(_task_cb), c_data)
+        })
+    }
+}
+
+/// Add an idle enterer handler.
+/// The closure is called when the main loop enters the idle state.
+pub fn idle_enterer_add(func: EcoreTaskClosure) -> IdleEnterer {
+    IdleEnterer {
+        _state: _task_state_new(func, |c_data| unsafe {
+            ecore_idle_enterer_add(transmute(_task_cb), c_data)
+        })
+    }
+}
+
+/// Add an idle enterer handler at the start of the list so it gets called
+/// earlier than others.
+pub fn idle_enterer_before_add(func: EcoreTaskClosure) -> IdleEnterer {
+    IdleEnterer {
+        _state: _task_state_new(func, |c_data| unsafe {
+            ecore_idle_enterer_before_add(transmute(_task_cb), c_data)
+        })
+    }
+}
+
+/// Add an idle exiter handler.
+/// The closure is called when the main loop exits the idle state.
+pub fn idle_exiter_add(func: EcoreTaskClosure) -> IdleExiter {
+    IdleExiter {
+        _state: _task_state_new(func, |c_data| unsafe {
+            ecore_idle_exiter_add(transmute(_task_cb), c_data)
+        })
+    }
+}
+
+/// Add an animator to call the closure at every animation tick.
+/// The animator runs until the closure returns false or the returned
+/// handle is dropped.
+pub fn animator_add(func: EcoreTaskClosure) -> Animator {
+    Animator {
+        _state: _task_state_new(func, |c_data| unsafe {
+            ecore_animator_add(transmute(_task_cb), c_data)
+        })
+    }
+}
+
+/// Add an animator that runs for a limited time.
+/// The closure receives the position (0.0 to 1.0) within 'runtime' seconds.
+pub fn animator_timeline_add(runtime: f64,
+                             func: EcoreTimelineClosure) -> AnimatorTimeline {
+    AnimatorTimeline {
+        _state: _task_state_new(func, |c_data| unsafe {
+            ecore_animator_timeline_add(runtime, transmute(_timeline_cb), c_data)
+        })
+    }
+}
+
+/// Maps an input position from 0.0 to 1.0 along a timeline to a
+/// position in a different curve.
+pub fn animator_pos_map(pos: f64, map: EcorePosMap, v1: f64, v2: f64) -> f64 {
+    unsafe { ecore_animator_pos_map(pos, map as c_uint, v1, v2) }
+}
+
+/// Set the animator call interval in seconds.
+pub fn animator_frametime_set(frametime: f64) {
+    unsafe { ecore_animator_frametime_set(frametime) }
+}
+
+/// Get the animator call interval in seconds.
+pub fn animator_frametime_get() -> f64 {
+    unsafe { ecore_animator_frametime_get() }
+}
+
+/* Move a closure to the heap and register it through 'add',
+ * which receives the state as the data of the C callback */
+fn _task_state_new<E, F>(func: F, add: |*c_void| -> *E) -> *mut _TaskState<E, F> {
+    unsafe {
+        let state: *mut _TaskState<E, F> = transmute(box _TaskState {
+            _eo: ptr::null(), func: func
+        });
+        (*state)._eo = add(transmute(state));
+        state
+    }
+}
+
+/* Run the task closure stored in 'data'.
+ * A cancelled task is already deleted by ecore, so only forget its object */
+extern "C" fn _task_cb(data: *c_void) -> u8 {
+    unsafe {
+        let state: &mut _TaskState<eo::Eo, EcoreTaskClosure> = transmute(data);
+        let renew = state.func.call_mut(());
+        if !renew { state._eo = ptr::null() }
+        from_bool_to_eina(renew)
+    }
+}
+
+/* Run the timeline closure stored in 'data'.
+ * The timeline is deleted by ecore when cancelled or once it reaches 1.0 */
+extern "C" fn _timeline_cb(data: *c_void, pos: f64) -> u8 {
+    unsafe {
+        let state: &mut _TaskState<eo::Eo, EcoreTimelineClosure> = transmute(data);
+        let renew = state.func.call_mut((pos,));
+        if !renew || pos >= 1.0 { state._eo = ptr::null() }
+        from_bool_to_eina(renew)
+    }
+}
+
+/// Add a callback for activity on the given file descriptor.
+/// The closure is called with the fd and the active conditions whenever
+/// one of the 'flags' conditions is met, until it returns false or the
+/// returned handle is dropped.
+pub fn main_fd_handler_add(fd: RawFd, flags: FdHandlerFlags,
+                           func: EcoreFdClosure) -> FdHandler {
+    FdHandler {
+        _state: _task_state_new(func, |c_data| unsafe {
+            ecore_main_fd_handler_add(fd, flags, transmute(_fd_cb), c_data,
+                                      transmute(ptr::null::<c_void>()), ptr::null())
+        })
+    }
+}
+
+/* Run the fd closure stored in 'data' with the conditions that fired */
+extern "C" fn _fd_cb(data: *c_void, fd_handler: *EcoreFdHandler) -> u8 {
+    unsafe {
+        let state: &mut _TaskState<EcoreFdHandler, EcoreFdClosure> = transmute(data);
+        let mut active: FdHandlerFlags = 0;
+        for flag in [ECORE_FD_READ, ECORE_FD_WRITE, ECORE_FD_ERROR].iter() {
+            if from_eina_to_bool(ecore_main_fd_handler_active_get(fd_handler, *flag)) {
+                active |= *flag;
+            }
+        }
+        let fd = ecore_main_fd_handler_fd_get(fd_handler);
+        let renew = state.func.call_mut((fd, active));
+        if !renew { state._eo = ptr::null() }
+        from_bool_to_eina(renew)
+    }
+}
+
+pub fn evas_init() -> int {
+    unsafe { ecore_evas_init() as int }
+}
+
+pub fn evas_shutdown() -> int {
+    unsafe { ecore_evas_shutdown() as int }
+}
+
+// Creates a new Ecore_Evas based on engine name and common parameters.
+// If 'engine_name' is None, it  use environment variable ECORE_EVAS_ENGINE,
+// that can be undefined and in this case this call will try to find the
+// first working engine.
+pub fn evas_new(engine_name: Option<&str>,
+                x: int, y: int,
+                w: int, h: int,
+                extra_options: &str) -> Box<EcoreEvas> {
+    unsafe {
+        transmute(match engine_name {
+            /* Null pointer */
+            None =>
+                extra_options.with_c_str(|c_extra_options| {
+                    ecore_evas_new(ptr::null(), x as c_int, y as c_int, 
+                                   w as c_int, h as c_int, c_extra_options)
+                }),
+            Some(ename) =>
+                ename.with_c_str(|c_engine_name| {
+                    extra_options.with_c_str(|c_extra_options| {
+                        ecore_evas_new(c_engine_name, x as c_int, y as c_int, 
+                                       w as c_int, h as c_int, c_extra_options)
+                    })
+                })
+        })
+    }
+}
+
+/// Show an Ecore_Evas' window.
+pub fn evas_show(ee: &EcoreEvas) {
+    unsafe { ecore_evas_show(ee) }
+}
+
+/// Get an Ecore_Evas's Evas.
+pub fn evas_get(ee: &EcoreEvas) -> Box<evas::Evas> {
+    unsafe { transmute(ecore_evas_get(ee)) }
+}
+
+/// Free an Ecore_Evas.
+pub fn evas_free(ee: &EcoreEvas) {
+    unsafe { ecore_evas_free(ee) }
+}
+
+/// Get the geometry of an Ecore_Evas.
+pub fn evas_geometry_get(ee: &EcoreEvas, x: &int, y: &int, w: &int, h: &int) {
+    unsafe {
+        ecore_evas_geometry_get(ee, transmute(x), transmute(y),
+                                transmute(w), transmute(h))
+    }
+}
+
+/// Set a callback for Ecore_Evas resize events.
+pub fn evas_callback_resize_set(ee: &EcoreEvas, func: EcoreEvasEventCb) {
+    unsafe {
+        ecore_evas_callback_resize_set(ee, transmute(func))
+    }
+}
+
+/// Store user data in an Ecore_Evas structure.
+pub fn evas_data_set<T>(ee: &EcoreEvas, key: &str, data: &T) {
+    key.with_c_str(|c_key| unsafe {
+        ecore_evas_data_set(ee, c_key, transmute(data))
+    })
+}
+
+/// Retrieve user data associated with an Ecore_Evas.
+pub fn evas_data_get<T>(ee: &EcoreEvas, key: &str) -> Box<T> {
+    key.with_c_str(|c_key| unsafe {
+        transmute(ecore_evas_data_get(ee, c_key))
+    })
+}
+
+impl EcoreEvasWindow {
+    /// Create a new window, with the same parameters as 'evas_new'.
+    /// It is boxed so that its callbacks can keep referring to it.
+    pub fn new(engine_name: Option<&str>,
+               x: int, y: int,
+               w: int, h: int,
+               extra_options: &str) -> Box<EcoreEvasWindow> {
+        let ee: *EcoreEvas = unsafe { transmute(evas_new(engine_name, x, y, w, h,
+                                                         extra_options)) };
+        EcoreEvasWindow::_from_raw(ee)
+    }
+
+    /// Create a window drawing into a memory buffer of the given size,
+    /// needing no display. The canvas is rendered on request with
+    /// 'manual_render' or when reading its pixels with 'buffer_pixels_get'.
+    pub fn buffer_new(w: int, h: int) -> Box<EcoreEvasWindow> {
+        EcoreEvasWindow::_from_raw(unsafe { ecore_evas_buffer_new(w as c_int, h as c_int) })
+    }
+
+    fn _from_raw(ee: *EcoreEvas) -> Box<EcoreEvasWindow> {
+        let state: *mut _WindowState = unsafe {
+            transmute(box _WindowState {
+                window: ptr::null(),
+                callbacks: Vec::from_fn(_WindowCbLast as uint, |_| None)
+            })
+        };
+        let window = box EcoreEvasWindow { _ee: ee, _state: state };
+        unsafe {
+            (*state).window = &*window;
+            if ee.is_not_null() {
+                _WINDOW_KEY.with_c_str(|c_key| {
+                    ecore_evas_data_set(ee, c_key, transmute(state))
+                })
+            }
+        }
+        window
+    }
+
+    /// Whether the window could be created.
+    pub fn is_valid(&self) -> bool {
+        self._ee.is_not_null()
+    }
+
+    /// Get the Ecore_Evas of the window, for use with the 'evas_*' functions.
+    pub fn ecore_evas_get<'r>(&'r self) -> &'r EcoreEvas {
+        unsafe { transmute(self._ee) }
+    }
+
+    /// Get the Evas canvas of the window.
+    pub fn evas_get<'r>(&'r self) -> &'r evas::Evas {
+        unsafe { transmute(ecore_evas_get(self._ee)) }
+    }
+
+    /// Show the window.
+    pub fn show(&self) {
+        unsafe { ecore_evas_show(self._ee) }
+    }
+
+    /// Hide the window.
+    pub fn hide(&self) {
+        unsafe { ecore_evas_hide(self._ee) }
+    }
+
+    /// Whether the window is visible.
+    pub fn visibility_get(&self) -> bool {
+        unsafe { ecore_evas_visibility_get(self._ee) != 0 }
+    }
+
+    /// Move the window to the given position.
+    pub fn move_to(&self, x: int, y: int) {
+        unsafe { ecore_evas_move(self._ee, x as c_int, y as c_int) }
+    }
+
+    /// Resize the window.
+    pub fn resize(&self, w: int, h: int) {
+        unsafe { ecore_evas_resize(self._ee, w as c_int, h as c_int) }
+    }
+
+    /// Move and resize the window at once.
+    pub fn move_resize(&self, x: int, y: int, w: int, h: int) {
+        unsafe {
+            ecore_evas_move_resize(self._ee, x as c_int, y as c_int,
+                                   w as c_int, h as c_int)
+        }
+    }
+
+    /// Get the geometry of the window as (x, y, width, height).
+    pub fn geometry_get(&self) -> (int, int, int, int) {
+        let (x, y, w, h): (c_int, c_int, c_int, c_int) = (0, 0, 0, 0);
+        unsafe { ecore_evas_geometry_get(self._ee, &x, &y, &w, &h) }
+        (x as int, y as int, w as int, h as int)
+    }
+
+    /// Set the title of the window.
+    pub fn title_set(&self, title: &str) {
+        title.with_c_str(|c_title| unsafe { ecore_evas_title_set(self._ee, c_title) })
+    }
+
+    /// Get the title of the window.
+    pub fn title_get(&self) -> String {
+        unsafe { from_c_to_string(ecore_evas_title_get(self._ee)).unwrap_or(String::new()) }
+    }
+
+    /// Set the window fullscreen or not.
+    pub fn fullscreen_set(&self, on: bool) {
+        unsafe { ecore_evas_fullscreen_set(self._ee, from_bool_to_eina(on)) }
+    }
+
+    /// Whether the window is fullscreen.
+    pub fn fullscreen_get(&self) -> bool {
+        unsafe { from_eina_to_bool(ecore_evas_fullscreen_get(self._ee)) }
+    }
+
+    /// Maximize the window or restore it.
+    pub fn maximized_set(&self, on: bool) {
+        unsafe { ecore_evas_maximized_set(self._ee, from_bool_to_eina(on)) }
+    }
+
+    /// Whether the window is maximized.
+    pub fn maximized_get(&self) -> bool {
+        unsafe { from_eina_to_bool(ecore_evas_maximized_get(self._ee)) }
+    }
+
+    /// Iconify the window or restore it.
+    pub fn iconified_set(&self, on: bool) {
+        unsafe { ecore_evas_iconified_set(self._ee, from_bool_to_eina(on)) }
+    }
+
+    /// Whether the window is iconified.
+    pub fn iconified_get(&self) -> bool {
+        unsafe { from_eina_to_bool(ecore_evas_iconified_get(self._ee)) }
+    }
+
+    /// Remove the window border or put it back.
+    pub fn borderless_set(&self, on: bool) {
+        unsafe { ecore_evas_borderless_set(self._ee, from_bool_to_eina(on)) }
+    }
+
+    /// Whether the window has no border.
+    pub fn borderless_get(&self) -> bool {
+        unsafe { from_eina_to_bool(ecore_evas_borderless_get(self._ee)) }
+    }
+
+    /// Set whether the window has an alpha channel.
+    pub fn alpha_set(&self, alpha: bool) {
+        unsafe { ecore_evas_alpha_set(self._ee, from_bool_to_eina(alpha)) }
+    }
+
+    /// Whether the window has an alpha channel.
+    pub fn alpha_get(&self) -> bool {
+        unsafe { from_eina_to_bool(ecore_evas_alpha_get(self._ee)) }
+    }
+
+    /// Set the rotation of the window, in degrees (0, 90, 180 or 270).
+    pub fn rotation_set(&self, rot: int) {
+        unsafe { ecore_evas_rotation_set(self._ee, rot as c_int) }
+    }
+
+    /// Get the rotation of the window, in degrees.
+    pub fn rotation_get(&self) -> int {
+        unsafe { ecore_evas_rotation_get(self._ee) as int }
+    }
+
+    /// Set whether the canvas is only rendered by 'manual_render',
+    /// instead of from the main loop.
+    pub fn manual_render_set(&self, manual_render: bool) {
+        unsafe { ecore_evas_manual_render_set(self._ee, from_bool_to_eina(manual_render)) }
+    }
+
+    /// Whether the canvas is only rendered by 'manual_render'.
+    pub fn manual_render_get(&self) -> bool {
+        unsafe { from_eina_to_bool(ecore_evas_manual_render_get(self._ee)) }
+    }
+
+    /// Render the canvas now.
+    pub fn manual_render(&self) {
+        unsafe { ecore_evas_manual_render(self._ee) }
+    }
+
+    /// Render a window created with 'buffer_new' and copy its pixels.
+    /// The pixels are premultiplied ARGB8888, stored row by row, with
+    /// as many rows and columns as the window size.
+    /// An empty vector is returned for other windows.
+    pub fn buffer_pixels_get(&self) -> Vec<u32> {
+        let (_, _, w, h) = self.geometry_get();
+        unsafe {
+            let pixels: *u32 = transmute(ecore_evas_buffer_pixels_get(self._ee));
+            if pixels.is_null() { return Vec::new() }
+            raw::from_buf(pixels, (w * h) as uint)
+        }
+    }
+
+    /// Call 'func' when the window manager asks to close the window.
+    /// Without this callback, the window is not closed.
+    pub fn callback_delete_request_set(&self, func: EcoreEvasWindowClosure) {
+        self._callback_set(_WindowCbDeleteRequest, func,
+                           ecore_evas_callback_delete_request_set, _window_delete_request_cb)
+    }
+
+    /// Call 'func' when the window is moved.
+    pub fn callback_move_set(&self, func: EcoreEvasWindowClosure) {
+        self._callback_set(_WindowCbMove, func,
+                           ecore_evas_callback_move_set, _window_move_cb)
+    }
+
+    /// Call 'func' when the window is resized.
+    pub fn callback_resize_set(&self, func: EcoreEvasWindowClosure) {
+        self._callback_set(_WindowCbResize, func,
+                           ecore_evas_callback_resize_set, _window_resize_cb)
+    }
+
+    /// Call 'func' when the window is shown.
+    pub fn callback_show_set(&self, func: EcoreEvasWindowClosure) {
+        self._callback_set(_WindowCbShow, func,
+                           ecore_evas_callback_show_set, _window_show_cb)
+    }
+
+    /// Call 'func' when the window is hidden.
+    pub fn callback_hide_set(&self, func: EcoreEvasWindowClosure) {
+        self._callback_set(_WindowCbHide, func,
+                           ecore_evas_callback_hide_set, _window_hide_cb)
+    }
+
+    /// Call 'func' when the window gets the focus.
+    pub fn callback_focus_in_set(&self, func: EcoreEvasWindowClosure) {
+        self._callback_set(_WindowCbFocusIn, func,
+                           ecore_evas_callback_focus_in_set, _window_focus_in_cb)
+    }
+
+    /// Call 'func' when the window loses the focus.
+    pub fn callback_focus_out_set(&self, func: EcoreEvasWindowClosure) {
+        self._callback_set(_WindowCbFocusOut, func,
+                           ecore_evas_callback_focus_out_set, _window_focus_out_cb)
+    }
+
+    /// Call 'func' just before the canvas is rendered.
+    pub fn callback_pre_render_set(&self, func: EcoreEvasWindowClosure) {
+        self._callback_set(_WindowCbPreRender, func,
+                           ecore_evas_callback_pre_render_set, _window_pre_render_cb)
+    }
+
+    /// Call 'func' just after the canvas is rendered.
+    pub fn callback_post_render_set(&self, func: EcoreEvasWindowClosure) {
+        self._callback_set(_WindowCbPostRender, func,
+                           ecore_evas_callback_post_render_set, _window_post_render_cb)
+    }
+
+    fn _callback_set(&self, which: _WindowCb, func: EcoreEvasWindowClosure,
+                     set: unsafe extern "C" fn (*EcoreEvas, _CEcoreEvasEventCb),
+                     cb: extern "C" fn (*EcoreEvas)) {
+        unsafe {
+            (*self._state).callbacks.as_mut_slice()[which as uint] = Some(func);
+            set(self._ee, transmute(cb))
+        }
+    }
+}
+
+#[unsafe_destructor]
+impl Drop for EcoreEvasWindow {
+    fn drop(&mut self) {
+        unsafe {
+            if self._ee.is_not_null() { ecore_evas_free(self._ee) }
+            drop(transmute::<*mut _WindowState, Box<_WindowState>>(self._state))
+        }
+    }
+}
+
+/* Run the window closure for the event 'which', if any */
+fn _window_cb_call(ee: *EcoreEvas, which: _WindowCb) {
+    unsafe {
+        let data = _WINDOW_KEY.with_c_str(|c_key| ecore_evas_data_get(ee, c_key));
+        if data.is_null() { return }
+        let state: &mut _WindowState = transmute(data);
+        let window: &EcoreEvasWindow = transmute(state.window);
+        match state.callbacks.as_mut_slice()[which as uint] {
+            None => (),
+            Some(ref mut func) => func.call_mut((window,))
+        }
+    }
+}
+
+extern "C" fn _window_delete_request_cb(ee: *EcoreEvas) {
+    _window_cb_call(ee, _WindowCbDeleteRequest)
+}
+
+extern "C" fn _window_move_cb(ee: *EcoreEvas) {
+    _window_cb_call(ee, _WindowCbMove)
+}
+
+extern "C" fn _window_resize_cb(ee: *EcoreEvas) {
+    _window_cb_call(ee, _WindowCbResize)
+}
+
+extern "C" fn _window_show_cb(ee: *EcoreEvas) {
+    _window_cb_call(ee, _WindowCbShow)
+}
+
+extern "C" fn _window_hide_cb(ee: *EcoreEvas) {
+    _window_cb_call(ee, _WindowCbHide)
+}
+
+extern "C" fn _window_focus_in_cb(ee: *EcoreEvas) {
+    _window_cb_call(ee, _WindowCbFocusIn)
+}
+
+extern "C" fn _window_focus_out_cb(ee: *EcoreEvas) {
+    _window_cb_call(ee, _WindowCbFocusOut)
+}
+
+extern "C" fn _window_pre_render_cb(ee: *EcoreEvas) {
+    _window_cb_call(ee, _WindowCbPreRender)
+}
+
+extern "C" fn _window_post_render_cb(ee: *EcoreEvas) {
+    _window_cb_call(ee, _WindowCbPostRender)
+}
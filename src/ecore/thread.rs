@@ -0,0 +1,265 @@
+// Ecore_Thread Rust bindings for EFL.
+// Copyright (C) 2014  Luis Araujo <araujoc.luisf@gmail.com>
+
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 2.1 of the License, or (at your option) any later version.
+
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+
+/*
+ * Run heavy work on the EFL worker pool.
+ * The blocking closure runs in a worker thread, while the feedback,
+ * done and cancel closures are always called back on the main loop.
+ */
+
+extern crate libc;
+
+use std::cell::RefCell;
+use std::mem::{transmute, replace};
+use std::ptr;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, SeqCst};
+
+use ecore::thread::libc::{c_int, c_void};
+use eina;
+use eseful::{from_bool_to_eina, from_eina_to_bool};
+
+
+pub enum EcoreThread {}
+
+/// Handle of a job running on the worker pool.
+/// It can be used from the main loop to cancel the job.
+pub struct Thread {
+    _eo: *EcoreThread,
+    _finished: Arc<AtomicBool>
+}
+
+/// Handle given to the blocking closure running in the worker thread.
+/// 'M' is the type of the feedback messages sent back to the main loop.
+pub struct Worker<M> {
+    _eo: *EcoreThread,
+    _messages: Arc<Mutex<Vec<M>>>
+}
+
+pub type ThreadBlockingProc<R, M> = proc(&Worker<M>):Send -> R;
+pub type ThreadFeedbackClosure<M> = Box<FnMut(M) + 'static>;
+
+/* Heap state shared by the worker thread and the main loop callbacks.
+ * Both only hold shared references to it: the fields the worker uses are
+ * behind locks, the closures are only touched from the main loop. */
+struct _ThreadState<R, M> {
+    func: Mutex<Option<ThreadBlockingProc<R, M>>>,
+    result: Mutex<Option<R>>,
+    // Feedback messages sent by the worker, not yet delivered.
+    messages: Arc<Mutex<Vec<M>>>,
+    on_feedback: RefCell<Option<ThreadFeedbackClosure<M>>>,
+    on_done: RefCell<Option<proc(R)>>,
+    on_cancel: RefCell<Option<proc()>>,
+    finished: Arc<AtomicBool>
+}
+
+/* C level callback notation */
+type _CEcoreThreadCb = fn (*c_void, *EcoreThread);
+type _CEcoreThreadNotifyCb = fn (*c_void, *EcoreThread, *c_void);
+
+#[link(name = "ecore")]
+extern "C" {
+    fn ecore_thread_run(func_blocking: _CEcoreThreadCb,
+                        func_end: _CEcoreThreadCb,
+                        func_cancel: _CEcoreThreadCb,
+                        data: *c_void) -> *EcoreThread;
+    fn ecore_thread_feedback_run(func_heavy: _CEcoreThreadCb,
+                                 func_notify: _CEcoreThreadNotifyCb,
+                                 func_end: _CEcoreThreadCb,
+                                 func_cancel: _CEcoreThreadCb,
+                                 data: *c_void,
+                                 try_no_queue: eina::EinaBool) -> *EcoreThread;
+    fn ecore_thread_cancel(thread: *EcoreThread) -> eina::EinaBool;
+    fn ecore_thread_check(thread: *EcoreThread) -> eina::EinaBool;
+    fn ecore_thread_feedback(thread: *EcoreThread, msg_data: *c_void) -> eina::EinaBool;
+    fn ecore_thread_active_get() -> c_int;
+    fn ecore_thread_pending_get() -> c_int;
+    fn ecore_thread_max_get() -> c_int;
+    fn ecore_thread_max_set(num: c_int);
+}
+
+
+impl Thread {
+    /// Cancel the job.
+    /// A job that has not started yet is dropped from the queue, a running
+    /// one is only flagged and should stop by checking 'Worker::is_cancelled'.
+    /// Returns true if the job was cancelled right away.
+    pub fn cancel(&self) -> bool {
+        if self.is_finished() { return false }
+        unsafe { from_eina_to_bool(ecore_thread_cancel(self._eo)) }
+    }
+
+    /// Whether the done or cancel closure of the job has already run.
+    pub fn is_finished(&self) -> bool {
+        self._finished.load(SeqCst)
+    }
+}
+
+impl<M: Send> Worker<M> {
+    /// Whether the job was cancelled from the main loop.
+    pub fn is_cancelled(&self) -> bool {
+        unsafe { from_eina_to_bool(ecore_thread_check(self._eo)) }
+    }
+
+    /// Send a message to the feedback closure on the main loop.
+    /// Messages still pending when the job ends or is cancelled are dropped.
+    pub fn feedback(&self, msg: M) -> bool {
+        self._messages.lock().push(msg);
+        // Ecore only wakes the main loop up, the message stays queued here.
+        let sent = unsafe { from_eina_to_bool(ecore_thread_feedback(self._eo, ptr::null())) };
+        // The message is never delivered if the main loop was not woken up.
+        if !sent { self._messages.lock().pop(); }
+        sent
+    }
+}
+
+/// Run 'func' on the worker pool.
+/// Its result is handed to 'on_done' on the main loop, or 'on_cancel' is
+/// called instead if the job was cancelled.
+pub fn run<R: Send>(func: ThreadBlockingProc<R, ()>,
+                    on_done: proc(R), on_cancel: proc()) -> Thread {
+    let state: _ThreadState<R, ()> = _thread_state_new(func, None, on_done, on_cancel);
+    let finished = state.finished.clone();
+    unsafe {
+        let c_data: *c_void = transmute(box state);
+        Thread {
+            _eo: ecore_thread_run(transmute(_blocking_cb::<R, ()>),
+                                  transmute(_end_cb::<R, ()>),
+                                  transmute(_cancel_cb::<R, ()>),
+                                  c_data),
+            _finished: finished
+        }
+    }
+}
+
+/// Run 'func' on the worker pool, delivering the messages it sends with
+/// 'Worker::feedback' to 'on_feedback' on the main loop.
+/// If 'try_no_queue' is true, a dedicated thread is started for the job
+/// instead of waiting for a free worker.
+pub fn feedback_run<R: Send, M: Send>(func: ThreadBlockingProc<R, M>,
+                                      on_feedback: ThreadFeedbackClosure<M>,
+                                      on_done: proc(R), on_cancel: proc(),
+                                      try_no_queue: bool) -> Thread {
+    let state: _ThreadState<R, M> =
+        _thread_state_new(func, Some(on_feedback), on_done, on_cancel);
+    let finished = state.finished.clone();
+    unsafe {
+        let c_data: *c_void = transmute(box state);
+        Thread {
+            _eo: ecore_thread_feedback_run(transmute(_blocking_cb::<R, M>),
+                                           transmute(_notify_cb::<R, M>),
+                                           transmute(_end_cb::<R, M>),
+                                           transmute(_cancel_cb::<R, M>),
+                                           c_data,
+                                           from_bool_to_eina(try_no_queue)),
+            _finished: finished
+        }
+    }
+}
+
+/// Get number of active threads running jobs.
+pub fn active_get() -> int {
+    unsafe { ecore_thread_active_get() as int }
+}
+
+/// Get number of queued jobs waiting for a free worker.
+pub fn pending_get() -> int {
+    unsafe { ecore_thread_pending_get() as int }
+}
+
+/// Get the maximum number of threads that can run simultaneously.
+pub fn max_get() -> int {
+    unsafe { ecore_thread_max_get() as int }
+}
+
+/// Set the maximum number of threads allowed to run simultaneously.
+pub fn max_set(num: int) {
+    unsafe { ecore_thread_max_set(num as c_int) }
+}
+
+fn _thread_state_new<R: Send, M: Send>(func: ThreadBlockingProc<R, M>,
+                                       on_feedback: Option<ThreadFeedbackClosure<M>>,
+                                       on_done: proc(R),
+                                       on_cancel: proc()) -> _ThreadState<R, M> {
+    _ThreadState {
+        func: Mutex::new(Some(func)),
+        result: Mutex::new(None),
+        messages: Arc::new(Mutex::new(Vec::new())),
+        on_feedback: RefCell::new(on_feedback),
+        on_done: RefCell::new(Some(on_done)),
+        on_cancel: RefCell::new(Some(on_cancel)),
+        finished: Arc::new(AtomicBool::new(false))
+    }
+}
+
+/* Worker thread: run the blocking proc and keep its result */
+extern "C" fn _blocking_cb<R: Send, M: Send>(data: *c_void, thread: *EcoreThread) {
+    let state: &_ThreadState<R, M> = unsafe { transmute(data) };
+    let func = state.func.lock().take();
+    match func {
+        None => (),
+        Some(func) => {
+            let worker: Worker<M> = Worker {
+                _eo: thread, _messages: state.messages.clone()
+            };
+            let result = func(&worker);
+            *state.result.lock() = Some(result);
+        }
+    }
+}
+
+/* Main loop: deliver the queued feedback messages */
+extern "C" fn _notify_cb<R: Send, M: Send>(data: *c_void, _thread: *EcoreThread,
+                                           _msg_data: *c_void) {
+    let state: &_ThreadState<R, M> = unsafe { transmute(data) };
+    // Do not hold the lock while the closure runs, the worker keeps sending.
+    let messages = replace(&mut *state.messages.lock(), Vec::new());
+    match *state.on_feedback.borrow_mut() {
+        None => (),
+        Some(ref mut func) => for msg in messages.move_iter() { func.call_mut((msg,)) }
+    }
+}
+
+/* Main loop: the job ended, hand its result over and free the state */
+extern "C" fn _end_cb<R: Send, M: Send>(data: *c_void, _thread: *EcoreThread) {
+    let state: Box<_ThreadState<R, M>> = unsafe { transmute(data) };
+    state.finished.store(true, SeqCst);
+    _drop_messages(&*state);
+    let result = state.result.lock().take();
+    match (state.on_done.borrow_mut().take(), result) {
+        (Some(on_done), Some(result)) => on_done(result),
+        _ => ()
+    }
+}
+
+/* Main loop: the job was cancelled, free the state */
+extern "C" fn _cancel_cb<R: Send, M: Send>(data: *c_void, _thread: *EcoreThread) {
+    let state: Box<_ThreadState<R, M>> = unsafe { transmute(data) };
+    state.finished.store(true, SeqCst);
+    _drop_messages(&*state);
+    match state.on_cancel.borrow_mut().take() {
+        None => (),
+        Some(on_cancel) => on_cancel()
+    }
+}
+
+/* Drop the feedback messages left undelivered: Ecore does not notify a
+ * cancelled job, and a failed wake up leaves its message queued */
+fn _drop_messages<R: Send, M: Send>(state: &_ThreadState<R, M>) {
+    let messages = replace(&mut *state.messages.lock(), Vec::new());
+    drop(messages)
+}
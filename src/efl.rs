@@ -20,6 +20,7 @@
 
 #![feature(macro_rules)]
 #![feature(unsafe_destructor)]
+#![feature(unboxed_closures)]
 
 pub mod eo;
 pub mod ecore;
@@ -32,3 +33,4 @@ pub mod eldbus;
 pub mod emotion;
 pub mod elementary;
 pub mod eseful;
+pub mod snapshot;
@@ -18,9 +18,14 @@
 extern crate libc;
 
 use eio::libc::{c_int, c_char, c_void, c_float, c_longlong, mode_t};
+use eio::libc::consts::os::posix88::EIO;
+use std::cell::Cell;
 use std::mem::transmute;
+use std::rc::Rc;
 
+use ecore::future;
 use eina;
+use eseful::from_c_to_string;
 
 pub enum EioFile {}
 
@@ -70,6 +75,14 @@ type _CEioErrorCb = fn (*c_void, *EioFile, c_int);
 pub type EioProgressCb<T> = fn (&mut T, &EioFile, &EioProgress);
 type _CEioProgressCb = fn (*c_void, *EioFile, *EioProgress);
 
+/* Data kept by 'file_ls_future' until the listing is done */
+struct _LsFutureState {
+    files: Vec<String>,
+    completer: future::Completer<Result<Vec<String>, int>>,
+    // Set once the done or error callback reclaimed the state.
+    finished: Rc<Cell<bool>>
+}
+
 #[link(name = "eio")]
 extern "C" {
     fn eio_init() -> c_int;
@@ -159,3 +172,54 @@ pub fn file_copy<T>(source: &str, dest: &str,
         })
     })
 }
+
+/// List contents of a directory without locking your app.
+/// The returned future resolves to the listed file names, or to the
+/// error code if the listing failed.
+pub fn file_ls_future(dir: &str) -> future::BoxFuture<Result<Vec<String>, int>> {
+    let (fut, completer) = future::oneshot();
+    let finished = Rc::new(Cell::new(false));
+    let state: Box<_LsFutureState> = box _LsFutureState {
+        files: Vec::new(), completer: completer, finished: finished.clone()
+    };
+    let c_state: *c_void = unsafe { transmute(state) };
+    let handler = dir.with_c_str(|c_dir| unsafe {
+        // The state is reclaimed by the done or error callback.
+        eio_file_ls(c_dir, transmute(_ls_future_filter_cb),
+                    transmute(_ls_future_main_cb), transmute(_ls_future_done_cb),
+                    transmute(_ls_future_error_cb), c_state)
+    });
+    // Eio may fail without calling back, then the state is still ours.
+    if handler.is_null() && !finished.get() {
+        let state: Box<_LsFutureState> = unsafe { transmute(c_state) };
+        let _LsFutureState { completer, .. } = *state;
+        completer.complete(Err(EIO as int))
+    }
+    box fut
+}
+
+fn _ls_future_filter_cb(_state: &mut _LsFutureState, _handler: &EioFile,
+                        _file: *c_char) -> bool {
+    true
+}
+
+fn _ls_future_main_cb(state: &mut _LsFutureState, _handler: &EioFile, file: *c_char) {
+    match unsafe { from_c_to_string(file) } {
+        None => (),
+        Some(f) => state.files.push(f)
+    }
+}
+
+fn _ls_future_done_cb(state: &mut _LsFutureState, _handler: &EioFile) {
+    let state: Box<_LsFutureState> = unsafe { transmute(state) };
+    let _LsFutureState { files, completer, finished } = *state;
+    finished.set(true);
+    completer.complete(Ok(files))
+}
+
+fn _ls_future_error_cb(state: &mut _LsFutureState, _handler: &EioFile, error: int) {
+    let state: Box<_LsFutureState> = unsafe { transmute(state) };
+    let _LsFutureState { completer, finished, .. } = *state;
+    finished.set(true);
+    completer.complete(Err(error))
+}
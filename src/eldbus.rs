@@ -18,15 +18,18 @@
 
 extern crate libc;
 
+use std::cell::Cell;
 use std::mem::transmute;
 use std::c_str::CString;
 use std::option::Option;
 use std::ptr;
+use std::rc::Rc;
 
 use eldbus::libc::{c_int, c_uint, c_char};
 // Re-export for macro usage
 pub use self::libc::{c_void, c_double};
 
+use ecore::future;
 use eina;
 use eseful::from_eina_to_bool;
 
@@ -45,6 +48,34 @@ pub enum EldbusMessage {}
 /// Represents a message that has been sent but has not yet reached its destination.
 pub enum EldbusPending {}
 
+/// A referenced Eldbus_Message, released when dropped.
+pub struct Message {
+    _eo: *EldbusMessage
+}
+
+impl Message {
+    /// Get the referenced message.
+    pub fn get<'r>(&'r self) -> &'r EldbusMessage {
+        unsafe { transmute(self._eo) }
+    }
+}
+
+impl Drop for Message {
+    fn drop(&mut self) {
+        unsafe { eldbus_message_unref(self._eo) }
+    }
+}
+
+/// Result of 'proxy_call_future': the reply or the error name and text.
+pub type ProxyCallResult = Result<Message, (String, String)>;
+
+/* Data kept by 'proxy_call_future' until the reply arrives */
+struct _ProxyCallState {
+    completer: future::Completer<ProxyCallResult>,
+    // Set once the message callback reclaimed the state.
+    finished: Rc<Cell<bool>>
+}
+
 pub enum EldbusConnectionType
 {
     EldbusConnectionTypeUnknown = 0,
@@ -74,6 +105,8 @@ extern "C" {
                          ...) -> *EldbusPending;
     fn eldbus_message_arguments_get(msg: *EldbusMessage, signature: *c_char,
  	                            ...) -> eina::EinaBool;
+    fn eldbus_message_ref(msg: *EldbusMessage) -> *EldbusMessage;
+    fn eldbus_message_unref(msg: *EldbusMessage);
     fn eldbus_proxy_unref(proxy: *EldbusProxy);
     fn eldbus_object_unref(obj:	*EldbusObject);
     fn eldbus_connection_unref(conn: *EldbusConnection);
@@ -140,6 +173,49 @@ pub fn proxy_call<T>(proxy: &EldbusProxy, member: &str,
     })
 }
 
+/// Call a method in proxy, sending no data value.
+/// The returned future resolves to the reply message or to the error.
+pub fn proxy_call_future(proxy: &EldbusProxy, member: &str,
+                         timeout: f64) -> future::BoxFuture<ProxyCallResult> {
+    let (fut, completer) = future::oneshot();
+    let finished = Rc::new(Cell::new(false));
+    let state: Box<_ProxyCallState> = box _ProxyCallState {
+        completer: completer, finished: finished.clone()
+    };
+    let c_state: *c_void = unsafe { transmute(state) };
+    let pending = member.with_c_str(|c_member| unsafe {
+        "".with_c_str(|c_signature| {
+            // The state is reclaimed by the message callback.
+            eldbus_proxy_call(proxy, c_member, transmute(_proxy_call_future_cb),
+                              c_state, timeout as c_double, c_signature)
+        })
+    });
+    // Eldbus may fail without calling back, then the state is still ours.
+    if pending.is_null() && !finished.get() {
+        let state: Box<_ProxyCallState> = unsafe { transmute(c_state) };
+        let _ProxyCallState { completer, .. } = *state;
+        completer.complete(Err(("org.freedesktop.DBus.Error.Failed".to_string(),
+                                "Could not send the method call".to_string())))
+    }
+    box fut
+}
+
+fn _proxy_call_future_cb(data: &_ProxyCallState,
+                         msg: &EldbusMessage, _pending: &EldbusPending) {
+    let state: Box<_ProxyCallState> = unsafe { transmute(data) };
+    let _ProxyCallState { completer, finished } = *state;
+    finished.set(true);
+    let mut errname: String = String::new();
+    let mut errmsg: String = String::new();
+
+    if message_error_get(msg, Some(&mut errname), Some(&mut errmsg)) {
+        completer.complete(Err((errname, errmsg)))
+    } else {
+        // The message is freed after this callback, keep a reference.
+        completer.complete(Ok(Message { _eo: unsafe { eldbus_message_ref(msg) } }))
+    }
+}
+
 /// Get the arguments from an Eldbus_Message.
 /// This is just a convenient function for receiving a value of signature T.
 /// To receive a variable list of values, use the message_arguments_get! macro.
@@ -22,6 +22,7 @@
 
 extern crate libc;
 
+use std::c_str::CString;
 use std::mem::forget;
 use eseful::libc::c_char;
 use eina;
@@ -61,3 +62,10 @@ pub fn from_eina_to_bool(eb: eina::EinaBool) -> bool {
         _ => false
     }
 }
+
+/// Copy a C string, replacing invalid UTF-8 sequences.
+/// Returns None for a NULL pointer.
+pub unsafe fn from_c_to_string(s: *c_char) -> Option<String> {
+    if s.is_null() { return None }
+    Some(String::from_utf8_lossy(CString::new(s, false).as_bytes_no_nul()).into_string())
+}
@@ -0,0 +1,586 @@
+// Evas input events Rust bindings for EFL.
+// Copyright (C) 2014  Luis Araujo <araujoc.luisf@gmail.com>
+
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 2.1 of the License, or (at your option) any later version.
+
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+
+/*
+ * Typed information of the input events received by Evas objects.
+ *
+ * Each event kind has its own struct, and the kind of a callback
+ * registered with 'object_event_closure_add' is chosen by the type
+ * of event its closure takes.
+ */
+
+extern crate libc;
+
+use std::mem::transmute;
+
+use evas::event::libc::{c_int, c_uint, c_char, c_void};
+use evas::{Evas, EvasObject, Coord, ButtonFlags, evas_object_event_callback_add};
+use evas::{EvasCallbackType, EvasCallbackMouseIn, EvasCallbackMouseOut};
+use evas::{EvasCallbackMouseDown, EvasCallbackMouseUp, EvasCallbackMouseMove};
+use evas::{EvasCallbackMouseWheel, EvasCallbackMultiDown, EvasCallbackMultiUp};
+use evas::{EvasCallbackMultiMove, EvasCallbackKeyDown, EvasCallbackKeyUp};
+use evas::{EvasCallbackHold, EvasCallbackFree};
+use eina;
+use eseful::{from_eina_to_bool, from_c_to_string};
+
+
+pub enum EvasModifier {}
+pub enum EvasLock {}
+
+/// Flags telling how an event was already handled.
+pub type EventFlags = c_uint;
+
+/// No fancy flags set
+pub static EVAS_EVENT_FLAG_NONE: EventFlags = 0;
+/// This event is being delivered but should be put on hold by the event receiver
+pub static EVAS_EVENT_FLAG_ON_HOLD: EventFlags = 1;
+/// This event flag indicates the event occurs while scrolling
+pub static EVAS_EVENT_FLAG_ON_SCROLL: EventFlags = 2;
+
+/// The keyboard modifiers (like "Shift" or "Control") held during an event.
+/// Only valid while the event callback runs.
+pub struct Modifiers {
+    _m: *EvasModifier
+}
+
+/// The keyboard locks (like "Caps_Lock" or "Num_Lock") set during an event.
+/// Only valid while the event callback runs.
+pub struct Locks {
+    _l: *EvasLock
+}
+
+/// An input event that objects can get.
+pub trait InputEvent {
+    /// The callback type of the event. The argument is only used
+    /// to choose the implementation, and is always None.
+    fn callback_type(_: Option<Self>) -> EvasCallbackType;
+    /// Build the event from the C event info of its callback.
+    unsafe fn from_c(info: *c_void) -> Self;
+}
+
+/// Closure receiving the object an event happened on and its typed info.
+pub type EvasObjectEventClosure<E> = Box<FnMut(&EvasObject, &E) + 'static>;
+
+/* Mouse button press or release */
+macro_rules! mouse_button_event(
+    ($(#[$attr:meta])* $name:ident, $cbtype:ident) => (
+        $(#[$attr])*
+        pub struct $name {
+            /// The button, starting from 1 for the left one.
+            pub button: int,
+            /// Position on the output, in pixels.
+            pub output: Coord,
+            /// Position on the canvas.
+            pub canvas: Coord,
+            pub modifiers: Modifiers,
+            pub locks: Locks,
+            pub flags: ButtonFlags,
+            pub timestamp: uint,
+            pub event_flags: EventFlags
+        }
+
+        impl InputEvent for $name {
+            fn callback_type(_: Option<$name>) -> EvasCallbackType { $cbtype }
+
+            unsafe fn from_c(info: *c_void) -> $name {
+                let ev: &_CMouseButton = transmute(info);
+                $name {
+                    button: ev.button as int,
+                    output: _coord(&ev.output),
+                    canvas: _coord(&ev.canvas),
+                    modifiers: Modifiers { _m: ev.modifiers },
+                    locks: Locks { _l: ev.locks },
+                    flags: ev.flags,
+                    timestamp: ev.timestamp as uint,
+                    event_flags: ev.event_flags
+                }
+            }
+        }
+    )
+)
+
+/* Mouse pointer entering or leaving an object */
+macro_rules! mouse_in_out_event(
+    ($(#[$attr:meta])* $name:ident, $cbtype:ident) => (
+        $(#[$attr])*
+        pub struct $name {
+            /// Mask of the pressed buttons, 1 being the left one.
+            pub buttons: int,
+            pub output: Coord,
+            pub canvas: Coord,
+            pub modifiers: Modifiers,
+            pub locks: Locks,
+            pub timestamp: uint,
+            pub event_flags: EventFlags
+        }
+
+        impl InputEvent for $name {
+            fn callback_type(_: Option<$name>) -> EvasCallbackType { $cbtype }
+
+            unsafe fn from_c(info: *c_void) -> $name {
+                let ev: &_CMouseInOut = transmute(info);
+                $name {
+                    buttons: ev.buttons as int,
+                    output: _coord(&ev.output),
+                    canvas: _coord(&ev.canvas),
+                    modifiers: Modifiers { _m: ev.modifiers },
+                    locks: Locks { _l: ev.locks },
+                    timestamp: ev.timestamp as uint,
+                    event_flags: ev.event_flags
+                }
+            }
+        }
+    )
+)
+
+/* Multi-touch press or release */
+macro_rules! multi_button_event(
+    ($(#[$attr:meta])* $name:ident, $cbtype:ident) => (
+        $(#[$attr])*
+        pub struct $name {
+            /// The multi-touch device, starting from 1.
+            pub device: int,
+            pub radius: f64,
+            pub radius_x: f64,
+            pub radius_y: f64,
+            pub pressure: f64,
+            pub angle: f64,
+            pub output: Coord,
+            pub canvas: Coord,
+            /// Sub-pixel position on the canvas.
+            pub canvas_sub: (f64, f64),
+            pub modifiers: Modifiers,
+            pub locks: Locks,
+            pub flags: ButtonFlags,
+            pub timestamp: uint,
+            pub event_flags: EventFlags
+        }
+
+        impl InputEvent for $name {
+            fn callback_type(_: Option<$name>) -> EvasCallbackType { $cbtype }
+
+            unsafe fn from_c(info: *c_void) -> $name {
+                let ev: &_CMultiButton = transmute(info);
+                $name {
+                    device: ev.device as int,
+                    radius: ev.radius,
+                    radius_x: ev.radius_x,
+                    radius_y: ev.radius_y,
+                    pressure: ev.pressure,
+                    angle: ev.angle,
+                    output: _coord(&ev.output),
+                    canvas: (ev.canvas.x as int, ev.canvas.y as int),
+                    canvas_sub: (ev.canvas.xsub, ev.canvas.ysub),
+                    modifiers: Modifiers { _m: ev.modifiers },
+                    locks: Locks { _l: ev.locks },
+                    flags: ev.flags,
+                    timestamp: ev.timestamp as uint,
+                    event_flags: ev.event_flags
+                }
+            }
+        }
+    )
+)
+
+/* Key press or release */
+macro_rules! key_event(
+    ($(#[$attr:meta])* $name:ident, $cbtype:ident) => (
+        $(#[$attr])*
+        pub struct $name {
+            /// Name of the physical key, like "Return" or "a".
+            pub keyname: String,
+            /// Logical key, taking the keyboard layout into account.
+            pub key: String,
+            /// Text produced by the key, if any.
+            pub string: Option<String>,
+            /// Text produced by a compose sequence, if any.
+            pub compose: Option<String>,
+            pub modifiers: Modifiers,
+            pub locks: Locks,
+            pub timestamp: uint,
+            pub event_flags: EventFlags,
+            /// Hardware key code.
+            pub keycode: uint
+        }
+
+        impl InputEvent for $name {
+            fn callback_type(_: Option<$name>) -> EvasCallbackType { $cbtype }
+
+            unsafe fn from_c(info: *c_void) -> $name {
+                let ev: &_CKey = transmute(info);
+                $name {
+                    keyname: from_c_to_string(ev.keyname).unwrap_or(String::new()),
+                    key: from_c_to_string(ev.key).unwrap_or(String::new()),
+                    string: from_c_to_string(ev.string),
+                    compose: from_c_to_string(ev.compose),
+                    modifiers: Modifiers { _m: ev.modifiers },
+                    locks: Locks { _l: ev.locks },
+                    timestamp: ev.timestamp as uint,
+                    event_flags: ev.event_flags,
+                    keycode: ev.keycode as uint
+                }
+            }
+        }
+    )
+)
+
+mouse_button_event!(
+    /// Mouse button pressed.
+    MouseDown, EvasCallbackMouseDown)
+mouse_button_event!(
+    /// Mouse button released.
+    MouseUp, EvasCallbackMouseUp)
+mouse_in_out_event!(
+    /// Mouse pointer entered an object.
+    MouseIn, EvasCallbackMouseIn)
+mouse_in_out_event!(
+    /// Mouse pointer left an object.
+    MouseOut, EvasCallbackMouseOut)
+multi_button_event!(
+    /// Multi-touch point pressed.
+    MultiDown, EvasCallbackMultiDown)
+multi_button_event!(
+    /// Multi-touch point released.
+    MultiUp, EvasCallbackMultiUp)
+key_event!(
+    /// Key pressed on a focused object.
+    KeyDown, EvasCallbackKeyDown)
+key_event!(
+    /// Key released on a focused object.
+    KeyUp, EvasCallbackKeyUp)
+
+/// Mouse pointer moved over an object.
+pub struct MouseMove {
+    /// Mask of the pressed buttons, 1 being the left one.
+    pub buttons: int,
+    /// Current position on the output and the canvas.
+    pub cur_output: Coord,
+    pub cur_canvas: Coord,
+    /// Previous position on the output and the canvas.
+    pub prev_output: Coord,
+    pub prev_canvas: Coord,
+    pub modifiers: Modifiers,
+    pub locks: Locks,
+    pub timestamp: uint,
+    pub event_flags: EventFlags
+}
+
+/// Mouse wheel turned over an object.
+pub struct MouseWheel {
+    /// 0 for the vertical wheel, 1 for the horizontal one.
+    pub direction: int,
+    /// Negative when scrolling up or left, positive otherwise.
+    pub z: int,
+    pub output: Coord,
+    pub canvas: Coord,
+    pub modifiers: Modifiers,
+    pub locks: Locks,
+    pub timestamp: uint,
+    pub event_flags: EventFlags
+}
+
+/// Multi-touch point moved over an object.
+pub struct MultiMove {
+    pub device: int,
+    pub radius: f64,
+    pub radius_x: f64,
+    pub radius_y: f64,
+    pub pressure: f64,
+    pub angle: f64,
+    pub output: Coord,
+    pub canvas: Coord,
+    pub canvas_sub: (f64, f64),
+    pub modifiers: Modifiers,
+    pub locks: Locks,
+    pub timestamp: uint,
+    pub event_flags: EventFlags
+}
+
+/// Object put on hold, or released from it.
+pub struct Hold {
+    pub hold: bool,
+    pub timestamp: uint,
+    pub event_flags: EventFlags
+}
+
+/* C representation of the events */
+struct _CPoint {
+    x: c_int,
+    y: c_int
+}
+
+struct _CPrecisionPoint {
+    x: c_int,
+    y: c_int,
+    xsub: f64,
+    ysub: f64
+}
+
+struct _CMouseButton {
+    button: c_int,
+    output: _CPoint,
+    canvas: _CPoint,
+    data: *c_void,
+    modifiers: *EvasModifier,
+    locks: *EvasLock,
+    flags: c_uint,
+    timestamp: c_uint,
+    event_flags: c_uint,
+    dev: *c_void
+}
+
+struct _CMouseInOut {
+    buttons: c_int,
+    output: _CPoint,
+    canvas: _CPoint,
+    data: *c_void,
+    modifiers: *EvasModifier,
+    locks: *EvasLock,
+    timestamp: c_uint,
+    event_flags: c_uint,
+    dev: *c_void
+}
+
+struct _CMouseMove {
+    buttons: c_int,
+    cur_output: _CPoint,
+    cur_canvas: _CPoint,
+    prev_output: _CPoint,
+    prev_canvas: _CPoint,
+    data: *c_void,
+    modifiers: *EvasModifier,
+    locks: *EvasLock,
+    timestamp: c_uint,
+    event_flags: c_uint,
+    dev: *c_void
+}
+
+struct _CMouseWheel {
+    direction: c_int,
+    z: c_int,
+    output: _CPoint,
+    canvas: _CPoint,
+    data: *c_void,
+    modifiers: *EvasModifier,
+    locks: *EvasLock,
+    timestamp: c_uint,
+    event_flags: c_uint,
+    dev: *c_void
+}
+
+struct _CMultiButton {
+    device: c_int,
+    radius: f64,
+    radius_x: f64,
+    radius_y: f64,
+    pressure: f64,
+    angle: f64,
+    output: _CPoint,
+    canvas: _CPrecisionPoint,
+    data: *c_void,
+    modifiers: *EvasModifier,
+    locks: *EvasLock,
+    flags: c_uint,
+    timestamp: c_uint,
+    event_flags: c_uint,
+    dev: *c_void
+}
+
+struct _CMultiMove {
+    device: c_int,
+    radius: f64,
+    radius_x: f64,
+    radius_y: f64,
+    pressure: f64,
+    angle: f64,
+    output: _CPoint,
+    canvas: _CPrecisionPoint,
+    data: *c_void,
+    modifiers: *EvasModifier,
+    locks: *EvasLock,
+    timestamp: c_uint,
+    event_flags: c_uint,
+    dev: *c_void
+}
+
+struct _CKey {
+    keyname: *c_char,
+    data: *c_void,
+    modifiers: *EvasModifier,
+    locks: *EvasLock,
+    key: *c_char,
+    string: *c_char,
+    compose: *c_char,
+    timestamp: c_uint,
+    event_flags: c_uint,
+    dev: *c_void,
+    keycode: c_uint
+}
+
+struct _CHold {
+    hold: c_int,
+    data: *c_void,
+    timestamp: c_uint,
+    event_flags: c_uint,
+    dev: *c_void
+}
+
+/* Heap state of a typed event callback, freed along with its object */
+struct _ObjectEventState<E> {
+    func: EvasObjectEventClosure<E>
+}
+
+
+#[link(name = "evas")]
+extern "C" {
+    fn evas_key_modifier_is_set(m: *EvasModifier, keyname: *c_char) -> eina::EinaBool;
+    fn evas_key_lock_is_set(l: *EvasLock, keyname: *c_char) -> eina::EinaBool;
+}
+
+
+impl Modifiers {
+    /// Whether the modifier 'keyname' (like "Shift") is held.
+    pub fn is_set(&self, keyname: &str) -> bool {
+        keyname.with_c_str(|c_keyname| unsafe {
+            from_eina_to_bool(evas_key_modifier_is_set(self._m, c_keyname))
+        })
+    }
+}
+
+impl Locks {
+    /// Whether the lock 'keyname' (like "Caps_Lock") is set.
+    pub fn is_set(&self, keyname: &str) -> bool {
+        keyname.with_c_str(|c_keyname| unsafe {
+            from_eina_to_bool(evas_key_lock_is_set(self._l, c_keyname))
+        })
+    }
+}
+
+impl InputEvent for MouseMove {
+    fn callback_type(_: Option<MouseMove>) -> EvasCallbackType { EvasCallbackMouseMove }
+
+    unsafe fn from_c(info: *c_void) -> MouseMove {
+        let ev: &_CMouseMove = transmute(info);
+        MouseMove {
+            buttons: ev.buttons as int,
+            cur_output: _coord(&ev.cur_output),
+            cur_canvas: _coord(&ev.cur_canvas),
+            prev_output: _coord(&ev.prev_output),
+            prev_canvas: _coord(&ev.prev_canvas),
+            modifiers: Modifiers { _m: ev.modifiers },
+            locks: Locks { _l: ev.locks },
+            timestamp: ev.timestamp as uint,
+            event_flags: ev.event_flags
+        }
+    }
+}
+
+impl InputEvent for MouseWheel {
+    fn callback_type(_: Option<MouseWheel>) -> EvasCallbackType { EvasCallbackMouseWheel }
+
+    unsafe fn from_c(info: *c_void) -> MouseWheel {
+        let ev: &_CMouseWheel = transmute(info);
+        MouseWheel {
+            direction: ev.direction as int,
+            z: ev.z as int,
+            output: _coord(&ev.output),
+            canvas: _coord(&ev.canvas),
+            modifiers: Modifiers { _m: ev.modifiers },
+            locks: Locks { _l: ev.locks },
+            timestamp: ev.timestamp as uint,
+            event_flags: ev.event_flags
+        }
+    }
+}
+
+impl InputEvent for MultiMove {
+    fn callback_type(_: Option<MultiMove>) -> EvasCallbackType { EvasCallbackMultiMove }
+
+    unsafe fn from_c(info: *c_void) -> MultiMove {
+        let ev: &_CMultiMove = transmute(info);
+        MultiMove {
+            device: ev.device as int,
+            radius: ev.radius,
+            radius_x: ev.radius_x,
+            radius_y: ev.radius_y,
+            pressure: ev.pressure,
+            angle: ev.angle,
+            output: _coord(&ev.output),
+            canvas: (ev.canvas.x as int, ev.canvas.y as int),
+            canvas_sub: (ev.canvas.xsub, ev.canvas.ysub),
+            modifiers: Modifiers { _m: ev.modifiers },
+            locks: Locks { _l: ev.locks },
+            timestamp: ev.timestamp as uint,
+            event_flags: ev.event_flags
+        }
+    }
+}
+
+impl InputEvent for Hold {
+    fn callback_type(_: Option<Hold>) -> EvasCallbackType { EvasCallbackHold }
+
+    unsafe fn from_c(info: *c_void) -> Hold {
+        let ev: &_CHold = transmute(info);
+        Hold {
+            hold: ev.hold != 0,
+            timestamp: ev.timestamp as uint,
+            event_flags: ev.event_flags
+        }
+    }
+}
+
+
+/// Call 'func' with the typed info of each event of kind 'E' on 'obj'.
+/// The kind of event is chosen by the type the closure takes, e.g.
+/// 'box |&mut: obj: &EvasObject, ev: &MouseDown| { ... }'.
+/// The closure lives as long as the object.
+pub fn object_event_closure_add<E: InputEvent>(obj: &EvasObject,
+                                               func: EvasObjectEventClosure<E>) {
+    let cbtype: EvasCallbackType = InputEvent::callback_type(None::<E>);
+    let cb: extern "C" fn (*c_void, *Evas, *EvasObject, *c_void) = _object_event_cb::<E>;
+    let free_cb: extern "C" fn (*c_void, *Evas, *EvasObject, *c_void) =
+        _object_event_free_cb::<E>;
+    unsafe {
+        let state: *mut _ObjectEventState<E> = transmute(box _ObjectEventState { func: func });
+        evas_object_event_callback_add(obj, cbtype as c_uint, transmute(cb), transmute(state));
+        evas_object_event_callback_add(obj, EvasCallbackFree as c_uint,
+                                       transmute(free_cb), transmute(state));
+    }
+}
+
+/* Convert the event info and run the closure stored in 'data' */
+extern "C" fn _object_event_cb<E: InputEvent>(data: *c_void, _e: *Evas,
+                                               obj: *EvasObject, info: *c_void) {
+    unsafe {
+        let state: &mut _ObjectEventState<E> = transmute(data);
+        let ev: E = InputEvent::from_c(info);
+        state.func.call_mut((&*obj, &ev))
+    }
+}
+
+/* Free the closure once its object is gone */
+extern "C" fn _object_event_free_cb<E: InputEvent>(data: *c_void, _e: *Evas,
+                                                    _obj: *EvasObject, _info: *c_void) {
+    unsafe {
+        drop(transmute::<*c_void, Box<_ObjectEventState<E>>>(data))
+    }
+}
+
+fn _coord(p: &_CPoint) -> Coord {
+    (p.x as int, p.y as int)
+}
+
@@ -0,0 +1,306 @@
+// Evas_Map Rust bindings for EFL.
+// Copyright (C) 2014  Luis Araujo <araujoc.luisf@gmail.com>
+
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 2.1 of the License, or (at your option) any later version.
+
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+
+/*
+ * Maps transform how objects are drawn, placing each of their corners
+ * anywhere on the canvas, in 2D or 3D. The geometry of the objects,
+ * and so the area receiving their events, does not change.
+ */
+
+extern crate libc;
+
+use std::ptr;
+
+use evas::map::libc::c_int;
+use evas::{EvasObject, Coord};
+use eina;
+use eseful::{from_bool_to_eina, from_eina_to_bool};
+
+
+enum _CEvasMap {}
+
+/// Owned map of the points of an object, freed on drop.
+/// Setting a map on an object copies it.
+/// Points are indexed from 0 to 'count_get() - 1', the point accessors
+/// fail on other indices.
+pub struct EvasMap {
+    _m: *_CEvasMap
+}
+
+
+#[link(name = "evas")]
+extern "C" {
+    fn evas_map_new(count: c_int) -> *_CEvasMap;
+    fn evas_map_free(m: *_CEvasMap);
+    fn evas_map_dup(m: *_CEvasMap) -> *_CEvasMap;
+    fn evas_map_count_get(m: *_CEvasMap) -> c_int;
+    fn evas_map_smooth_set(m: *_CEvasMap, enabled: eina::EinaBool);
+    fn evas_map_smooth_get(m: *_CEvasMap) -> eina::EinaBool;
+    fn evas_map_alpha_set(m: *_CEvasMap, enabled: eina::EinaBool);
+    fn evas_map_alpha_get(m: *_CEvasMap) -> eina::EinaBool;
+    fn evas_map_util_points_populate_from_object(m: *_CEvasMap, obj: *EvasObject);
+    fn evas_map_util_points_populate_from_object_full(m: *_CEvasMap, obj: *EvasObject,
+                                                      z: c_int);
+    fn evas_map_util_points_populate_from_geometry(m: *_CEvasMap, x: c_int, y: c_int,
+                                                   w: c_int, h: c_int, z: c_int);
+    fn evas_map_util_points_color_set(m: *_CEvasMap, r: c_int, g: c_int, b: c_int, a: c_int);
+    fn evas_map_util_rotate(m: *_CEvasMap, degrees: f64, cx: c_int, cy: c_int);
+    fn evas_map_util_zoom(m: *_CEvasMap, zoomx: f64, zoomy: f64, cx: c_int, cy: c_int);
+    fn evas_map_util_3d_rotate(m: *_CEvasMap, dx: f64, dy: f64, dz: f64,
+                               cx: c_int, cy: c_int, cz: c_int);
+    fn evas_map_util_3d_perspective(m: *_CEvasMap, px: c_int, py: c_int,
+                                    z0: c_int, foc: c_int);
+    fn evas_map_util_3d_lighting(m: *_CEvasMap, lx: c_int, ly: c_int, lz: c_int,
+                                 lr: c_int, lg: c_int, lb: c_int,
+                                 ar: c_int, ag: c_int, ab: c_int);
+    fn evas_map_util_clockwise_get(m: *_CEvasMap) -> eina::EinaBool;
+    fn evas_map_point_coord_set(m: *_CEvasMap, idx: c_int, x: c_int, y: c_int, z: c_int);
+    fn evas_map_point_coord_get(m: *_CEvasMap, idx: c_int,
+                                x: *mut c_int, y: *mut c_int, z: *mut c_int);
+    fn evas_map_point_image_uv_set(m: *_CEvasMap, idx: c_int, u: f64, v: f64);
+    fn evas_map_point_image_uv_get(m: *_CEvasMap, idx: c_int, u: *mut f64, v: *mut f64);
+    fn evas_map_point_color_set(m: *_CEvasMap, idx: c_int,
+                                r: c_int, g: c_int, b: c_int, a: c_int);
+    fn evas_map_point_color_get(m: *_CEvasMap, idx: c_int, r: *mut c_int, g: *mut c_int,
+                                b: *mut c_int, a: *mut c_int);
+    fn evas_object_map_set(obj: *EvasObject, map: *_CEvasMap);
+    fn evas_object_map_get(obj: *EvasObject) -> *_CEvasMap;
+    fn evas_object_map_enable_set(obj: *EvasObject, enabled: eina::EinaBool);
+    fn evas_object_map_enable_get(obj: *EvasObject) -> eina::EinaBool;
+}
+
+
+impl EvasMap {
+    /// Create a map of 'count' points. Only maps of 4 points are
+    /// supported by Evas for now, None is returned for other counts.
+    pub fn new(count: int) -> Option<EvasMap> {
+        let m = unsafe { evas_map_new(count as c_int) };
+        if m.is_null() { None } else { Some(EvasMap { _m: m }) }
+    }
+
+    /// Create a map of 4 points matching the geometry of 'obj'.
+    pub fn from_object(obj: &EvasObject) -> EvasMap {
+        let mut map = match EvasMap::new(4) {
+            None => fail!("Cannot allocate a map of 4 points"),
+            Some(map) => map
+        };
+        map.util_points_populate_from_object(obj);
+        map
+    }
+
+    /// Number of points of the map.
+    pub fn count_get(&self) -> int {
+        unsafe { evas_map_count_get(self._m) as int }
+    }
+
+    /// Set whether the map is drawn with smooth scaling.
+    pub fn smooth_set(&mut self, enabled: bool) {
+        unsafe { evas_map_smooth_set(self._m, from_bool_to_eina(enabled)) }
+    }
+
+    /// Whether the map is drawn with smooth scaling.
+    pub fn smooth_get(&self) -> bool {
+        unsafe { from_eina_to_bool(evas_map_smooth_get(self._m)) }
+    }
+
+    /// Set whether the map takes the alpha channel of the object into account.
+    pub fn alpha_set(&mut self, enabled: bool) {
+        unsafe { evas_map_alpha_set(self._m, from_bool_to_eina(enabled)) }
+    }
+
+    /// Whether the map takes the alpha channel of the object into account.
+    pub fn alpha_get(&self) -> bool {
+        unsafe { from_eina_to_bool(evas_map_alpha_get(self._m)) }
+    }
+
+    /// Set the points to the corners of 'obj', at z 0, with matching
+    /// image coordinates.
+    pub fn util_points_populate_from_object(&mut self, obj: &EvasObject) {
+        unsafe { evas_map_util_points_populate_from_object(self._m, obj) }
+    }
+
+    /// Set the points to the corners of 'obj', at the given z.
+    pub fn util_points_populate_from_object_full(&mut self, obj: &EvasObject, z: int) {
+        unsafe { evas_map_util_points_populate_from_object_full(self._m, obj, z as c_int) }
+    }
+
+    /// Set the points to the corners of the given rectangle, at the given z.
+    pub fn util_points_populate_from_geometry(&mut self, xy: Coord, wh: Coord, z: int) {
+        let ((x, y), (w, h)) = (xy, wh);
+        unsafe {
+            evas_map_util_points_populate_from_geometry(self._m, x as c_int, y as c_int,
+                                                        w as c_int, h as c_int, z as c_int)
+        }
+    }
+
+    /// Set the colour of all the points.
+    pub fn util_points_color_set(&mut self, r: int, g: int, b: int, a: int) {
+        unsafe {
+            evas_map_util_points_color_set(self._m, r as c_int, g as c_int,
+                                           b as c_int, a as c_int)
+        }
+    }
+
+    /// Rotate the points by 'degrees' clockwise around the centre 'c'.
+    pub fn util_rotate(&mut self, degrees: f64, c: Coord) {
+        let (cx, cy) = c;
+        unsafe { evas_map_util_rotate(self._m, degrees, cx as c_int, cy as c_int) }
+    }
+
+    /// Scale the points around the centre 'c'.
+    pub fn util_zoom(&mut self, zoomx: f64, zoomy: f64, c: Coord) {
+        let (cx, cy) = c;
+        unsafe { evas_map_util_zoom(self._m, zoomx, zoomy, cx as c_int, cy as c_int) }
+    }
+
+    /// Rotate the points around each axis by the given degrees,
+    /// with the centre at 'c' and depth 'cz'.
+    pub fn util_3d_rotate(&mut self, dx: f64, dy: f64, dz: f64, c: Coord, cz: int) {
+        let (cx, cy) = c;
+        unsafe {
+            evas_map_util_3d_rotate(self._m, dx, dy, dz, cx as c_int, cy as c_int, cz as c_int)
+        }
+    }
+
+    /// Apply a perspective projection seen from 'p', with the z plane
+    /// at 'z0' and the focal distance 'foc'.
+    pub fn util_3d_perspective(&mut self, p: Coord, z0: int, foc: int) {
+        let (px, py) = p;
+        unsafe {
+            evas_map_util_3d_perspective(self._m, px as c_int, py as c_int,
+                                         z0 as c_int, foc as c_int)
+        }
+    }
+
+    /// Shade the points with a light at 'l' and depth 'lz' of colour
+    /// 'light', and an ambient colour 'ambient', as (r, g, b).
+    pub fn util_3d_lighting(&mut self, l: Coord, lz: int,
+                            light: (int, int, int), ambient: (int, int, int)) {
+        let ((lx, ly), (lr, lg, lb), (ar, ag, ab)) = (l, light, ambient);
+        unsafe {
+            evas_map_util_3d_lighting(self._m, lx as c_int, ly as c_int, lz as c_int,
+                                      lr as c_int, lg as c_int, lb as c_int,
+                                      ar as c_int, ag as c_int, ab as c_int)
+        }
+    }
+
+    /// Whether the points are in clockwise order, i.e. the front of
+    /// the object is visible.
+    pub fn util_clockwise_get(&self) -> bool {
+        unsafe { from_eina_to_bool(evas_map_util_clockwise_get(self._m)) }
+    }
+
+    /// Set the canvas coordinates of the point 'idx'.
+    pub fn point_coord_set(&mut self, idx: uint, x: int, y: int, z: int) {
+        self._check_idx(idx);
+        unsafe {
+            evas_map_point_coord_set(self._m, idx as c_int, x as c_int, y as c_int, z as c_int)
+        }
+    }
+
+    /// Get the canvas coordinates of the point 'idx', as (x, y, z).
+    pub fn point_coord_get(&self, idx: uint) -> (int, int, int) {
+        self._check_idx(idx);
+        let (mut x, mut y, mut z): (c_int, c_int, c_int) = (0, 0, 0);
+        unsafe { evas_map_point_coord_get(self._m, idx as c_int, &mut x, &mut y, &mut z) }
+        (x as int, y as int, z as int)
+    }
+
+    /// Set the image coordinates (in pixels of the object) of the point 'idx'.
+    pub fn point_image_uv_set(&mut self, idx: uint, u: f64, v: f64) {
+        self._check_idx(idx);
+        unsafe { evas_map_point_image_uv_set(self._m, idx as c_int, u, v) }
+    }
+
+    /// Get the image coordinates of the point 'idx', as (u, v).
+    pub fn point_image_uv_get(&self, idx: uint) -> (f64, f64) {
+        self._check_idx(idx);
+        let (mut u, mut v) = (0.0f64, 0.0f64);
+        unsafe { evas_map_point_image_uv_get(self._m, idx as c_int, &mut u, &mut v) }
+        (u, v)
+    }
+
+    /// Set the colour of the point 'idx'.
+    pub fn point_color_set(&mut self, idx: uint, r: int, g: int, b: int, a: int) {
+        self._check_idx(idx);
+        unsafe {
+            evas_map_point_color_set(self._m, idx as c_int, r as c_int, g as c_int,
+                                     b as c_int, a as c_int)
+        }
+    }
+
+    /// Get the colour of the point 'idx', as (r, g, b, a).
+    pub fn point_color_get(&self, idx: uint) -> (int, int, int, int) {
+        self._check_idx(idx);
+        let (mut r, mut g, mut b, mut a): (c_int, c_int, c_int, c_int) = (0, 0, 0, 0);
+        unsafe {
+            evas_map_point_color_get(self._m, idx as c_int, &mut r, &mut g, &mut b, &mut a)
+        }
+        (r as int, g as int, b as int, a as int)
+    }
+
+    /* Evas does not check the point index, fail on one out of the map */
+    fn _check_idx(&self, idx: uint) {
+        let count = self.count_get() as uint;
+        if idx >= count { fail!("Point {} out of a map of {} points", idx, count) }
+    }
+}
+
+impl Clone for EvasMap {
+    fn clone(&self) -> EvasMap {
+        let m = unsafe { evas_map_dup(self._m) };
+        if m.is_null() { fail!("Cannot duplicate the map") }
+        EvasMap { _m: m }
+    }
+}
+
+impl Drop for EvasMap {
+    fn drop(&mut self) {
+        unsafe { evas_map_free(self._m) }
+    }
+}
+
+/// Set (a copy of) the map of an object, or remove it with None.
+/// The map is only used once enabled with 'object_map_enable_set'.
+pub fn object_map_set(obj: &EvasObject, map: Option<&EvasMap>) {
+    unsafe {
+        match map {
+            None => evas_object_map_set(obj, ptr::null()),
+            Some(m) => evas_object_map_set(obj, m._m)
+        }
+    }
+}
+
+/// Get a copy of the map of an object.
+pub fn object_map_get(obj: &EvasObject) -> Option<EvasMap> {
+    unsafe {
+        let m = evas_object_map_get(obj);
+        if m.is_null() { return None }
+        let copy = evas_map_dup(m);
+        if copy.is_null() { None } else { Some(EvasMap { _m: copy }) }
+    }
+}
+
+/// Enable or disable the map of an object.
+pub fn object_map_enable_set(obj: &EvasObject, enabled: bool) {
+    unsafe { evas_object_map_enable_set(obj, from_bool_to_eina(enabled)) }
+}
+
+/// Whether the map of an object is enabled.
+pub fn object_map_enable_get(obj: &EvasObject) -> bool {
+    unsafe { from_eina_to_bool(evas_object_map_enable_get(obj)) }
+}
@@ -0,0 +1,1391 @@
+// Evas Rust bindings for EFL.
+// Copyright (C) 2014  Luis Araujo <araujoc.luisf@gmail.com>
+
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 2.1 of the License, or (at your option) any later version.
+
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+
+
+extern crate libc;
+
+use evas::libc::{c_int, c_uint, c_char, c_void, c_short};
+use std::mem::transmute;
+use std::num::FromPrimitive;
+use std::option::Option;
+use std::ptr;
+use std::raw::Slice;
+
+use ecore;
+use eo;
+use eina;
+use eseful;
+
+pub mod event;
+pub mod text;
+pub mod smart;
+pub mod map;
+
+pub static EVAS_HINT_EXPAND: f64 = 1.0f64;
+pub static EVAS_HINT_FILL: f64 = -1.0f64;
+
+/// Flags for mouse and multi-touch button events.
+pub type ButtonFlags = c_uint;
+
+/// No extra mouse button data
+pub static EVAS_BUTTON_NONE: ButtonFlags = 0;
+/// This mouse button press was the 2nd press of a double click
+pub static EVAS_BUTTON_DOUBLE_CLICK: ButtonFlags = 1;
+/// This mouse button press was the 3rd press of a triple click
+pub static EVAS_BUTTON_TRIPLE_CLICK: ButtonFlags = 2;
+
+pub enum Evas {}
+
+/// The types of events triggering a callback.
+pub enum EvasCallbackType {
+    /// Mouse In Event
+    EvasCallbackMouseIn,
+    /// Mouse Out Event
+    EvasCallbackMouseOut,
+    /// Mouse Button Down Event
+    EvasCallbackMouseDown,
+    /// Mouse Button Up Event
+    EvasCallbackMouseUp,
+    /// Mouse Move Event
+    EvasCallbackMouseMove,
+    /// Mouse Wheel Event
+    EvasCallbackMouseWheel,
+    /// Multi-touch Down Event
+    EvasCallbackMultiDown,
+    /// Multi-touch Up Event
+    EvasCallbackMultiUp,
+    /// Multi-touch Move Event
+    EvasCallbackMultiMove,
+    /// Object Being Freed (Called after Del)
+    EvasCallbackFree,
+    /// Key Press Event
+    EvasCallbackKeyDown,
+    /// Key Release Event
+    EvasCallbackKeyUp,
+    /// Focus In Event
+    EvasCallbackFocusIn,
+    /// Focus Out Event
+    EvasCallbackFocusOut,
+    /// Show Event
+    EvasCallbackShow,
+    /// Hide Event
+    EvasCallbackHide,
+    /// Move Event
+    EvasCallbackMove,
+    /// Resize Event
+    EvasCallbackResize,
+    /// Restack Event
+    EvasCallbackRestack,
+    /// Object Being Deleted (called before Free)
+    EvasCallbackDel,
+    /// Events go on/off hold
+    EvasCallbackHold,
+    /// Size hints changed event
+    EvasCallbackChangedSizeHints,
+    /// Image has been preloaded
+    EvasCallbackImagePrealoaded,
+    /// Canvas got focus as a whole
+    EvasCallbackCanvasFocusIn,
+    /// Canvas lost focus as a whole
+    EvasCallbackCanvasFocusOut,
+    /// Called just before rendering is updated on the canvas target
+    EvasCallbackRenderFlushPre,
+    /// Called just after rendering is updated on the canvas target
+    EvasCallbackRenderFlushPost,
+    /// Canvas object got focus
+    EvasCallbackCanvasObjectFocusIn,
+    /// Canvas object lost focus
+    EvasCallbackCanvasObjectFocusOut,
+    /// Image data has been unloaded (by some mechanism in Evas that throw out original image data)
+    EvasCallbackImageUnloaded,
+    /// Called just before rendering starts on the canvas target
+    EvasCallbackRenderPre,
+    /// Called just after rendering stops on the canvas target
+    EvasCallbackRenderPost,
+    /// Image size is changed
+    EvasCallbackImageResize,
+    /// Devices added, removed or changed on canvas
+    EvasCallbackDeviceChanged,
+    /// kept as last element/sentinel -- not really an event
+    EvasCallbackLast
+}
+
+pub type EvasObject = eo::Eo;
+
+pub type Coord = (int, int);
+
+/// A RGBA colour.
+pub type Color = (int, int, int, int);
+
+/// Geometry as (x, y, width, height).
+pub type Geometry = (int, int, int, int);
+
+/// Padding as (left, right, top, bottom).
+pub type Padding = (int, int, int, int);
+
+/// Reasons for an image object to fail loading its file.
+#[deriving(PartialEq, Show, FromPrimitive)]
+pub enum EvasLoadError {
+    /// A non-specific error occurred
+    EvasLoadErrorGeneric = 1,
+    /// File (or file path) does not exist
+    EvasLoadErrorDoesNotExist,
+    /// Permission denied to an existing file (or path)
+    EvasLoadErrorPermissionDenied,
+    /// Allocation of resources failure prevented load
+    EvasLoadErrorResourceAllocationFailed,
+    /// File corrupt (but was detected as a known format)
+    EvasLoadErrorCorruptFile,
+    /// File is not a known format
+    EvasLoadErrorUnknownFormat
+}
+
+/// Pixel format of the data of an image object.
+#[deriving(PartialEq, Show, FromPrimitive)]
+pub enum EvasColorspace {
+    /// ARGB 32 bits per pixel, high-byte is Alpha, accessed 1 32bit word at a time
+    EvasColorspaceArgb8888 = 0,
+    /// YCbCr 4:2:2 Planar, ITU.BT-601 specifications
+    EvasColorspaceYcbcr422p601Pl,
+    /// YCbCr 4:2:2 Planar, ITU.BT-709 specifications
+    EvasColorspaceYcbcr422p709Pl,
+    /// 16bit rgb565 + Alpha plane at end - 5 bits of the 8 being used per alpha byte
+    EvasColorspaceRgb565A5p,
+    /// 8bit grayscale
+    EvasColorspaceGry8,
+    /// YCbCr 4:2:2, ITU.BT-601 specifications
+    EvasColorspaceYcbcr422601Pl,
+    /// YCbCr 4:2:0, ITU.BT-601 specification
+    EvasColorspaceYcbcr420nv12601Pl,
+    /// YCbCr 4:2:0, ITU.BT-601 specification, tiled
+    EvasColorspaceYcbcr420tm12601Pl,
+    /// AY 8bits Alpha and 8bits Grey, accessed 1 16bits at a time
+    EvasColorspaceAgry88
+}
+
+/// Read access to the pixels of an image object,
+/// from 'object_image_data_get'.
+pub struct ImageData<'r> {
+    _obj: &'r EvasObject,
+    _data: &'r [u32]
+}
+
+/// Write access to the pixels of an image object,
+/// from 'object_image_data_get_mut'.
+pub struct ImageDataMut<'r> {
+    _obj: &'r EvasObject,
+    _data: &'r mut [u32]
+}
+
+/* Key of the object data holding the borrow state of the image data */
+static _IMAGE_BORROW_KEY: &'static str = "_rust_efl_image_borrow";
+
+/// How an object's aspect ratio hint is applied by its container.
+#[deriving(PartialEq, Show, FromPrimitive)]
+pub enum EvasAspectControl {
+    /// Preference on scaling unset
+    EvasAspectControlNone = 0,
+    /// Same effect as unset preference on scaling
+    EvasAspectControlNeither,
+    /// Use all horizontal container space to place an object
+    EvasAspectControlHorizontal,
+    /// Use all vertical container space to place an object
+    EvasAspectControlVertical,
+    /// Use all horizontal and vertical container spaces to place an object
+    EvasAspectControlBoth
+}
+
+/// Display mode size hint of an object.
+#[deriving(PartialEq, Show, FromPrimitive)]
+pub enum EvasDisplayMode {
+    /// Default mode
+    EvasDisplayModeNone = 0,
+    /// Use this mode when you want to give compress display mode hint to an object
+    EvasDisplayModeCompress,
+    /// Use this mode when you want to give expand display mode hint to an object
+    EvasDisplayModeExpand,
+    /// Use this mode when an object should not change its display mode
+    EvasDisplayModeDontChange
+}
+
+/// Iterator over a list of Evas objects.
+/// Lists created for the caller are freed when the iterator is dropped.
+pub struct EvasObjects<'r> {
+    _list: *mut eina::_EinaList<'r, EvasObject>,
+    _it: eina::EinaList<'r, EvasObject>,
+    _owned: bool
+}
+
+/// A touch point of a multi-touch event.
+pub struct MultiPoint {
+    /// The multi-touch device, starting from 1 (0 is the mouse).
+    pub device: int,
+    pub x: int,
+    pub y: int,
+    pub radius: f64,
+    pub radius_x: f64,
+    pub radius_y: f64,
+    pub pressure: f64,
+    pub angle: f64,
+    /// Position with sub-pixel precision.
+    pub fx: f64,
+    pub fy: f64
+}
+
+/* A step of an EventScript */
+enum _ScriptStep {
+    _MouseMove(int, int),
+    _MouseDown(int, ButtonFlags),
+    _MouseUp(int, ButtonFlags),
+    _MouseWheel(int, int),
+    _KeyDown(String, String),
+    _KeyUp(String, String),
+    _Hold(bool)
+}
+
+/// A sequence of input events to feed to a canvas, built step by step.
+/// Events are fed in order with increasing timestamps once 'run' is called.
+pub struct EventScript {
+    _steps: Vec<_ScriptStep>
+}
+
+pub type EvasObjectEventCb<T> = fn (&T, &Evas, &EvasObject, &eseful::EventInfo);
+type _CEvasObjectEventCb = fn (*c_void, *Evas, *EvasObject, *c_void);
+
+/* High level callback notation */
+pub type EvasSmartCb<T> = fn (&Option<T>, &EvasObject, &eseful::EventInfo) -> ();
+/* C level callback notation */
+type CEvasSmartCb = fn (*c_void, *EvasObject, *c_void) -> c_void;
+
+
+#[link(name = "evas")]
+extern "C"  {
+    fn evas_init() -> c_int;
+    fn evas_shutdown() -> c_int;
+    fn evas_new() -> *Evas;
+    fn evas_free(e: *Evas);
+    fn evas_focus_in(e: *Evas);
+    fn evas_focus_out(e: *Evas);
+    fn evas_object_rectangle_add(e: *Evas) -> *EvasObject;
+    fn evas_object_line_add(e: *Evas) -> *EvasObject;
+    fn evas_object_line_xy_set(obj: *EvasObject, x1: c_int, y1: c_int, x2: c_int, y2: c_int);
+    fn evas_object_line_xy_get(obj: *EvasObject, x1: *mut c_int, y1: *mut c_int,
+                               x2: *mut c_int, y2: *mut c_int);
+    fn evas_object_polygon_add(e: *Evas) -> *EvasObject;
+    fn evas_object_polygon_point_add(obj: *EvasObject, x: c_int, y: c_int);
+    fn evas_object_polygon_points_clear(obj: *EvasObject);
+    fn evas_object_show(e: *EvasObject);
+    fn evas_object_resize(e: *EvasObject, w: c_int, h: c_int);
+    fn evas_object_del(obj: *EvasObject);	
+    fn evas_object_move(e: *EvasObject, x: c_int, y: c_int);
+    fn evas_object_name_set(obj: *EvasObject, name: *c_char); 
+    fn evas_object_color_set(obj: *EvasObject,
+                             r: c_int, g: c_int,
+                             b: c_int, a: c_int);
+    fn evas_object_size_hint_min_set(e: *EvasObject, x: c_int, y: c_int);
+    fn evas_object_size_hint_weight_set(e: *EvasObject, x: f64, y: f64);
+    fn evas_object_size_hint_align_set(e: *EvasObject, x: f64, y: f64);
+    fn evas_object_focus_set(obj: *EvasObject, focus: eina::EinaBool);
+    fn evas_object_image_add(e: *Evas) -> *EvasObject;
+    fn evas_object_image_filled_add(e: *Evas) -> *EvasObject;
+    fn evas_object_image_fill_set(obj: *EvasObject,
+                                  x: c_int, y: c_int,
+                                  w: c_int, h: c_int);
+    fn evas_object_image_file_set(obj: *EvasObject, file: *c_char, key: *c_char);
+    fn evas_object_image_size_set(obj: *EvasObject, w: c_int, h: c_int);
+    fn evas_object_image_size_get(obj: *EvasObject, w: *mut c_int, h: *mut c_int);
+    fn evas_object_image_save(obj: *EvasObject, file: *c_char, key: *c_char,
+                              flags: *c_char) -> eina::EinaBool;
+    fn evas_object_image_filled_set(obj: *EvasObject, setting: eina::EinaBool);
+    fn evas_object_image_preload(obj: *EvasObject, cancel: eina::EinaBool);
+    fn evas_object_image_data_get(obj: *EvasObject, for_writing: eina::EinaBool) -> *c_void;
+    fn evas_object_image_data_set(obj: *EvasObject, data: *c_void);
+    fn evas_object_image_data_copy_set(obj: *EvasObject, data: *c_void);
+    fn evas_object_image_data_update_add(obj: *EvasObject, x: c_int, y: c_int,
+                                         w: c_int, h: c_int);
+    fn evas_object_image_stride_get(obj: *EvasObject) -> c_int;
+    fn evas_object_image_alpha_set(obj: *EvasObject, has_alpha: eina::EinaBool);
+    fn evas_object_image_alpha_get(obj: *EvasObject) -> eina::EinaBool;
+    fn evas_object_image_colorspace_set(obj: *EvasObject, cspace: c_uint);
+    fn evas_object_image_colorspace_get(obj: *EvasObject) -> c_uint;
+    fn evas_object_image_smooth_scale_set(obj: *EvasObject, smooth_scale: eina::EinaBool);
+    fn evas_object_image_smooth_scale_get(obj: *EvasObject) -> eina::EinaBool;
+    fn evas_object_image_border_set(obj: *EvasObject, l: c_int, r: c_int,
+                                    t: c_int, b: c_int);
+    fn evas_object_image_border_get(obj: *EvasObject, l: *mut c_int, r: *mut c_int,
+                                    t: *mut c_int, b: *mut c_int);
+    fn evas_object_image_load_error_get(obj: *EvasObject) -> c_int;
+    fn evas_object_image_extension_can_load_get(file: *c_char) -> eina::EinaBool;
+    fn evas_object_evas_get(obj: *EvasObject) -> *Evas;
+    fn evas_object_geometry_get(obj: *EvasObject, x: *mut c_int, y: *mut c_int,
+                                w: *mut c_int, h: *mut c_int);
+    fn evas_event_feed_mouse_move(e: *Evas, x: c_int, y: c_int,
+                                  timestamp: c_uint, data: *c_void);
+    fn evas_event_feed_mouse_down(e: *Evas, b: c_int, flags: c_uint,
+                                  timestamp: c_uint, data: *c_void);
+    fn evas_event_feed_mouse_up(e: *Evas, b: c_int, flags: c_uint,
+                                timestamp: c_uint, data: *c_void);
+    fn evas_event_feed_mouse_wheel(e: *Evas, direction: c_int, z: c_int,
+                                   timestamp: c_uint, data: *c_void);
+    fn evas_event_feed_key_down(e: *Evas, keyname: *c_char, key: *c_char,
+                                string: *c_char, compose: *c_char,
+                                timestamp: c_uint, data: *c_void);
+    fn evas_event_feed_key_up(e: *Evas, keyname: *c_char, key: *c_char,
+                              string: *c_char, compose: *c_char,
+                              timestamp: c_uint, data: *c_void);
+    fn evas_event_feed_multi_down(e: *Evas, d: c_int, x: c_int, y: c_int,
+                                  rad: f64, radx: f64, rady: f64, pres: f64, ang: f64,
+                                  fx: f64, fy: f64, flags: c_uint,
+                                  timestamp: c_uint, data: *c_void);
+    fn evas_event_feed_multi_up(e: *Evas, d: c_int, x: c_int, y: c_int,
+                                rad: f64, radx: f64, rady: f64, pres: f64, ang: f64,
+                                fx: f64, fy: f64, flags: c_uint,
+                                timestamp: c_uint, data: *c_void);
+    fn evas_event_feed_multi_move(e: *Evas, d: c_int, x: c_int, y: c_int,
+                                  rad: f64, radx: f64, rady: f64, pres: f64, ang: f64,
+                                  fx: f64, fy: f64, timestamp: c_uint, data: *c_void);
+    fn evas_event_feed_hold(e: *Evas, hold: c_int, timestamp: c_uint, data: *c_void);
+    fn evas_object_geometry_set(obj: *EvasObject, x: c_int, y: c_int, w: c_int, h: c_int);
+    fn evas_object_visible_get(obj: *EvasObject) -> eina::EinaBool;
+    fn evas_object_color_get(obj: *EvasObject, r: *mut c_int, g: *mut c_int,
+                             b: *mut c_int, a: *mut c_int);
+    fn evas_object_name_get(obj: *EvasObject) -> *c_char;
+    fn evas_object_name_find(e: *Evas, name: *c_char) -> *EvasObject;
+    fn evas_object_type_get(obj: *EvasObject) -> *c_char;
+    fn evas_object_size_hint_min_get(obj: *EvasObject, w: *mut c_int, h: *mut c_int);
+    fn evas_object_size_hint_max_set(obj: *EvasObject, w: c_int, h: c_int);
+    fn evas_object_size_hint_max_get(obj: *EvasObject, w: *mut c_int, h: *mut c_int);
+    fn evas_object_size_hint_request_set(obj: *EvasObject, w: c_int, h: c_int);
+    fn evas_object_size_hint_request_get(obj: *EvasObject, w: *mut c_int, h: *mut c_int);
+    fn evas_object_size_hint_aspect_set(obj: *EvasObject, aspect: c_uint,
+                                        w: c_int, h: c_int);
+    fn evas_object_size_hint_aspect_get(obj: *EvasObject, aspect: *mut c_uint,
+                                        w: *mut c_int, h: *mut c_int);
+    fn evas_object_size_hint_padding_set(obj: *EvasObject, l: c_int, r: c_int,
+                                         t: c_int, b: c_int);
+    fn evas_object_size_hint_padding_get(obj: *EvasObject, l: *mut c_int, r: *mut c_int,
+                                         t: *mut c_int, b: *mut c_int);
+    fn evas_object_size_hint_display_mode_set(obj: *EvasObject, dispmode: c_uint);
+    fn evas_object_size_hint_display_mode_get(obj: *EvasObject) -> c_uint;
+    fn evas_object_size_hint_weight_get(obj: *EvasObject, x: *mut f64, y: *mut f64);
+    fn evas_object_size_hint_align_get(obj: *EvasObject, x: *mut f64, y: *mut f64);
+    fn evas_object_clip_set(obj: *EvasObject, clip: *EvasObject);
+    fn evas_object_clip_unset(obj: *EvasObject);
+    fn evas_object_clip_get(obj: *EvasObject) -> *EvasObject;
+    fn evas_object_clipees_get(obj: *EvasObject) -> *eina::_CEinaList;
+    fn evas_object_layer_set(obj: *EvasObject, l: c_short);
+    fn evas_object_layer_get(obj: *EvasObject) -> c_short;
+    fn evas_object_raise(obj: *EvasObject);
+    fn evas_object_lower(obj: *EvasObject);
+    fn evas_object_stack_above(obj: *EvasObject, above: *EvasObject);
+    fn evas_object_stack_below(obj: *EvasObject, below: *EvasObject);
+    fn evas_object_above_get(obj: *EvasObject) -> *EvasObject;
+    fn evas_object_below_get(obj: *EvasObject) -> *EvasObject;
+    fn evas_object_top_get(e: *Evas) -> *EvasObject;
+    fn evas_object_bottom_get(e: *Evas) -> *EvasObject;
+    fn evas_object_top_at_xy_get(e: *Evas, x: c_int, y: c_int,
+                                 include_pass_events_objects: eina::EinaBool,
+                                 include_hidden_objects: eina::EinaBool) -> *EvasObject;
+    fn evas_objects_at_xy_get(e: *Evas, x: c_int, y: c_int,
+                              include_pass_events_objects: eina::EinaBool,
+                              include_hidden_objects: eina::EinaBool) -> *eina::_CEinaList;
+    fn evas_object_pass_events_set(obj: *EvasObject, pass: eina::EinaBool);
+    fn evas_object_pass_events_get(obj: *EvasObject) -> eina::EinaBool;
+    fn evas_object_repeat_events_set(obj: *EvasObject, repeat: eina::EinaBool);
+    fn evas_object_repeat_events_get(obj: *EvasObject) -> eina::EinaBool;
+    fn evas_object_propagate_events_set(obj: *EvasObject, prop: eina::EinaBool);
+    fn evas_object_propagate_events_get(obj: *EvasObject) -> eina::EinaBool;
+    fn evas_object_data_set(obj: *EvasObject, key: *c_char, data: *c_void);
+    fn evas_object_data_get(obj: *EvasObject, key: *c_char) -> *c_void;
+    fn evas_object_data_del(obj: *EvasObject, key: *c_char) -> *c_void;
+    fn evas_object_event_callback_add(obj: *EvasObject, cbtype: c_uint,
+                                      func: _CEvasObjectEventCb, data: *c_void);
+    fn evas_object_smart_callback_add(e: *EvasObject, event: *c_char,
+                                      cb: CEvasSmartCb, data: *c_void);
+}
+
+
+pub fn init() -> int {
+    unsafe { evas_init() as int }
+}
+
+pub fn shutdown() -> int {
+    unsafe { evas_shutdown() as int }
+}
+
+pub fn new() -> Box<Evas> {
+    unsafe { transmute(evas_new()) }
+}
+
+pub fn free(e: &Evas) {
+    unsafe { evas_free(e) }
+}
+
+pub fn focus_in(e: &Evas) {
+    unsafe { evas_focus_in(e) }
+}
+
+pub fn focus_out(e: &Evas) {
+    unsafe { evas_focus_out(e) }
+}
+
+pub fn object_move(e: &EvasObject, c: Coord) {
+    let (x, y) = c;
+    unsafe { evas_object_move(e, x as c_int, y as c_int) }
+}
+
+pub fn object_name_set(obj: &EvasObject, name: &str) {
+    name.with_c_str(|c_name| unsafe {
+        evas_object_name_set(obj, c_name)
+    })
+}
+
+pub fn object_color_set(obj: &EvasObject, r: int, g: int, b: int, a: int) {
+    unsafe { 
+        evas_object_color_set(obj,
+                              r as c_int,
+                              g as c_int,
+                              b as c_int,
+                              a as c_int)
+    }
+}
+
+pub fn object_resize(e: &EvasObject, w: int, h: int) {
+    unsafe { evas_object_resize(e, w as c_int, h as c_int) }
+}
+
+/// Get the position and size of the object, as (x, y, width, height).
+pub fn object_geometry_get(obj: &EvasObject) -> Geometry {
+    let (mut x, mut y, mut w, mut h): (c_int, c_int, c_int, c_int) = (0, 0, 0, 0);
+    unsafe { evas_object_geometry_get(obj, &mut x, &mut y, &mut w, &mut h) }
+    (x as int, y as int, w as int, h as int)
+}
+
+/// Move and resize the object at once.
+pub fn object_geometry_set(obj: &EvasObject, g: Geometry) {
+    let (x, y, w, h) = g;
+    unsafe { evas_object_geometry_set(obj, x as c_int, y as c_int, w as c_int, h as c_int) }
+}
+
+/// Whether the object is shown.
+pub fn object_visible_get(obj: &EvasObject) -> bool {
+    unsafe { eseful::from_eina_to_bool(evas_object_visible_get(obj)) }
+}
+
+/// Get the colour of the object, as premultiplied (r, g, b, a).
+pub fn object_color_get(obj: &EvasObject) -> Color {
+    let (mut r, mut g, mut b, mut a): (c_int, c_int, c_int, c_int) = (0, 0, 0, 0);
+    unsafe { evas_object_color_get(obj, &mut r, &mut g, &mut b, &mut a) }
+    (r as int, g as int, b as int, a as int)
+}
+
+/// Get the name of the object, if it has one.
+pub fn object_name_get(obj: &EvasObject) -> Option<String> {
+    unsafe { eseful::from_c_to_string(evas_object_name_get(obj)) }
+}
+
+/// Find an object of the canvas by its name.
+pub fn object_name_find<'r>(e: &'r Evas, name: &str) -> Option<&'r EvasObject> {
+    name.with_c_str(|c_name| _object_option(unsafe { evas_object_name_find(e, c_name) }))
+}
+
+/// Get the type name of the object, e.g. "rectangle" or "image".
+pub fn object_type_get(obj: &EvasObject) -> Option<String> {
+    unsafe { eseful::from_c_to_string(evas_object_type_get(obj)) }
+}
+
+/// Marks the given Evas object for deletion (when Evas will free its memory).
+pub fn object_del(obj: &EvasObject) {
+    unsafe { evas_object_del(obj) }
+}
+
+pub fn object_size_hint_min_set(e: &EvasObject, w: int, h: int) {
+    unsafe { evas_object_size_hint_min_set(e, w as c_int, h as c_int) }
+}
+
+/// Get the minimum size hint of the object.
+pub fn object_size_hint_min_get(obj: &EvasObject) -> Coord {
+    let (mut w, mut h): (c_int, c_int) = (0, 0);
+    unsafe { evas_object_size_hint_min_get(obj, &mut w, &mut h) }
+    (w as int, h as int)
+}
+
+/// Set the maximum size hint of the object, -1 meaning no limit.
+pub fn object_size_hint_max_set(obj: &EvasObject, w: int, h: int) {
+    unsafe { evas_object_size_hint_max_set(obj, w as c_int, h as c_int) }
+}
+
+/// Get the maximum size hint of the object.
+pub fn object_size_hint_max_get(obj: &EvasObject) -> Coord {
+    let (mut w, mut h): (c_int, c_int) = (0, 0);
+    unsafe { evas_object_size_hint_max_get(obj, &mut w, &mut h) }
+    (w as int, h as int)
+}
+
+/// Set the preferred size hint of the object.
+pub fn object_size_hint_request_set(obj: &EvasObject, w: int, h: int) {
+    unsafe { evas_object_size_hint_request_set(obj, w as c_int, h as c_int) }
+}
+
+/// Get the preferred size hint of the object.
+pub fn object_size_hint_request_get(obj: &EvasObject) -> Coord {
+    let (mut w, mut h): (c_int, c_int) = (0, 0);
+    unsafe { evas_object_size_hint_request_get(obj, &mut w, &mut h) }
+    (w as int, h as int)
+}
+
+/// Set the aspect ratio hint of the object as w/h, and how it is applied.
+pub fn object_size_hint_aspect_set(obj: &EvasObject, aspect: EvasAspectControl,
+                                   w: int, h: int) {
+    unsafe {
+        evas_object_size_hint_aspect_set(obj, aspect as c_uint, w as c_int, h as c_int)
+    }
+}
+
+/// Get the aspect ratio hint of the object as (control, w, h).
+pub fn object_size_hint_aspect_get(obj: &EvasObject) -> (EvasAspectControl, int, int) {
+    let (mut aspect, mut w, mut h): (c_uint, c_int, c_int) = (0, 0, 0);
+    unsafe { evas_object_size_hint_aspect_get(obj, &mut aspect, &mut w, &mut h) }
+    let v: Option<EvasAspectControl> = FromPrimitive::from_u32(aspect);
+    (v.unwrap_or(EvasAspectControlNone), w as int, h as int)
+}
+
+/// Set the padding hint of the object, as (left, right, top, bottom).
+pub fn object_size_hint_padding_set(obj: &EvasObject, p: Padding) {
+    let (l, r, t, b) = p;
+    unsafe {
+        evas_object_size_hint_padding_set(obj, l as c_int, r as c_int, t as c_int, b as c_int)
+    }
+}
+
+/// Get the padding hint of the object, as (left, right, top, bottom).
+pub fn object_size_hint_padding_get(obj: &EvasObject) -> Padding {
+    let (mut l, mut r, mut t, mut b): (c_int, c_int, c_int, c_int) = (0, 0, 0, 0);
+    unsafe { evas_object_size_hint_padding_get(obj, &mut l, &mut r, &mut t, &mut b) }
+    (l as int, r as int, t as int, b as int)
+}
+
+/// Set the display mode hint of the object.
+pub fn object_size_hint_display_mode_set(obj: &EvasObject, mode: EvasDisplayMode) {
+    unsafe { evas_object_size_hint_display_mode_set(obj, mode as c_uint) }
+}
+
+/// Get the display mode hint of the object.
+pub fn object_size_hint_display_mode_get(obj: &EvasObject) -> EvasDisplayMode {
+    let v: Option<EvasDisplayMode> =
+        FromPrimitive::from_u32(unsafe { evas_object_size_hint_display_mode_get(obj) });
+    v.unwrap_or(EvasDisplayModeNone)
+}
+
+pub fn object_size_hint_weight_set(e: &EvasObject, x: f64, y: f64) {
+    unsafe { evas_object_size_hint_weight_set(e, x, y) }
+}
+
+/// Get the weight hint of the object, as (x, y).
+pub fn object_size_hint_weight_get(obj: &EvasObject) -> (f64, f64) {
+    let (mut x, mut y) = (0f64, 0f64);
+    unsafe { evas_object_size_hint_weight_get(obj, &mut x, &mut y) }
+    (x, y)
+}
+
+pub fn object_size_hint_align_set(e: &EvasObject, x: f64, y: f64) {
+    unsafe { evas_object_size_hint_align_set(e, x, y) }
+}
+
+/// Get the alignment hint of the object, as (x, y).
+pub fn object_size_hint_align_get(obj: &EvasObject) -> (f64, f64) {
+    let (mut x, mut y) = (0f64, 0f64);
+    unsafe { evas_object_size_hint_align_get(obj, &mut x, &mut y) }
+    (x, y)
+}
+
+pub fn object_focus_set(obj: &EvasObject, focus: eina::EinaBool) {
+    unsafe { evas_object_focus_set(obj, focus) }
+}
+
+pub fn object_show(e: &EvasObject) {
+    unsafe { evas_object_show(e) } 
+}
+
+/// Creates a new image object on the given Evas e canvas.
+pub fn object_image_add(e: &Evas) -> Box<EvasObject> {
+    unsafe { transmute(evas_object_image_add(e)) }
+}
+
+/// Creates a new image object that automatically scales its bound image to
+/// the object's area, on both axis.
+pub fn object_image_filled_add(e: &Evas) -> Box<EvasObject> {
+    unsafe { transmute(evas_object_image_filled_add(e)) }
+}
+
+/// Set how to fill an image object's drawing rectangle given the (real)
+/// image bound to it.
+pub fn object_image_fill_set(obj: *EvasObject, xy: Coord, wh: Coord) {
+    let (x, y) = xy;
+    let (w, h) = wh;
+    unsafe {
+        evas_object_image_fill_set(obj,
+                                   x as c_int, y as c_int,
+                                   w as c_int, h as c_int)
+    }
+}
+
+/// Set the source file from where an image object must fetch the real
+/// image data (it may be an Eet file, besides pure image ones).
+pub fn object_image_file_set(obj: &EvasObject, file: &str, key: Option<&str>) {
+    file.with_c_str(|c_file| unsafe {
+        match key {
+            None => evas_object_image_file_set(obj, c_file, ptr::null()),
+            Some(ref k) =>
+                k.with_c_str(|c_key|
+                             evas_object_image_file_set(obj, c_file, c_key))
+        }
+    })
+}
+
+/// Sets the size of the given image object.
+pub fn object_image_size_set(obj: &EvasObject, w: int, h: int) {
+    unsafe { evas_object_image_size_set(obj, w as c_int, h as c_int) }
+}
+
+/// Get the size of the given image object, as (width, height).
+pub fn object_image_size_get(obj: &EvasObject) -> Coord {
+    let (mut w, mut h): (c_int, c_int) = (0, 0);
+    unsafe { evas_object_image_size_get(obj, &mut w, &mut h) }
+    (w as int, h as int)
+}
+
+/// Save the image data of the given image object to 'file'.
+/// The 'flags' are a space separated list of options for the saver,
+/// like "quality=100 compress=9".
+pub fn object_image_save(obj: &EvasObject, file: &str, key: Option<&str>,
+                         flags: Option<&str>) -> bool {
+    let c_flags = flags.map(|f| f.to_c_str());
+    let c_key = key.map(|k| k.to_c_str());
+    file.with_c_str(|c_file| unsafe {
+        eseful::from_eina_to_bool(
+            evas_object_image_save(obj, c_file,
+                                   match c_key { None => ptr::null(), Some(ref k) => k.as_ptr() },
+                                   match c_flags { None => ptr::null(), Some(ref f) => f.as_ptr() }))
+    })
+}
+
+/// Save the image to 'file', with an optional 'quality' (0 to 100, used
+/// by lossy formats like JPEG) and 'compress' level (0 to 9, used by
+/// lossless formats like PNG).
+pub fn object_image_save_with_flags(obj: &EvasObject, file: &str, key: Option<&str>,
+                                    quality: Option<uint>, compress: Option<uint>) -> bool {
+    let mut flags: Vec<String> = Vec::new();
+    match quality {
+        None => (),
+        Some(q) => flags.push(format!("quality={}", q))
+    }
+    match compress {
+        None => (),
+        Some(c) => flags.push(format!("compress={}", c))
+    }
+    match flags.is_empty() {
+        true => object_image_save(obj, file, key, None),
+        false => object_image_save(obj, file, key, Some(flags.connect(" ").as_slice()))
+    }
+}
+
+/// Get the error of the last file set on the image object, if any.
+pub fn object_image_load_error_get(obj: &EvasObject) -> Result<(), EvasLoadError> {
+    match unsafe { evas_object_image_load_error_get(obj) } {
+        0 => Ok(()),
+        e => {
+            let v: Option<EvasLoadError> = FromPrimitive::from_i32(e);
+            Err(v.unwrap_or(EvasLoadErrorGeneric))
+        }
+    }
+}
+
+/// Whether Evas has a loader for the extension of 'file'.
+pub fn object_image_extension_can_load_get(file: &str) -> bool {
+    file.with_c_str(|c_file| unsafe {
+        eseful::from_eina_to_bool(evas_object_image_extension_can_load_get(c_file))
+    })
+}
+
+/// Get the number of bytes per row of the image data.
+pub fn object_image_stride_get(obj: &EvasObject) -> int {
+    unsafe { evas_object_image_stride_get(obj) as int }
+}
+
+/// Get read access to the ARGB8888 pixels of the image, or None if the
+/// image has no data, is in another colorspace or is being written.
+/// The pixels are given back to the image when the last reader is dropped.
+pub fn object_image_data_get<'r>(obj: &'r EvasObject) -> Option<ImageData<'r>> {
+    let readers = _image_borrow_get(obj);
+    if readers < 0 { return None }
+    match _image_data(obj) {
+        None => None,
+        Some(data) => {
+            _image_borrow_set(obj, readers + 1);
+            Some(ImageData { _obj: obj, _data: data })
+        }
+    }
+}
+
+/// Get write access to the ARGB8888 pixels of the image, or None if the
+/// image has no data, is in another colorspace or is already borrowed.
+/// The pixels are given back to the image when the guard is dropped;
+/// mark the changes with 'object_image_data_update_add'.
+pub fn object_image_data_get_mut<'r>(obj: &'r EvasObject) -> Option<ImageDataMut<'r>> {
+    if _image_borrow_get(obj) != 0 { return None }
+    match _image_data_mut(obj) {
+        None => None,
+        Some(data) => {
+            _image_borrow_set(obj, -1);
+            Some(ImageDataMut { _obj: obj, _data: data })
+        }
+    }
+}
+
+/// Set the pixel data of the image, without copying it.
+/// Panics if 'data' holds less than 'stride_get / 4' pixels per row
+/// for the image height.
+/// This is unsafe as Evas keeps using 'data' after the call: it must
+/// stay alive, unmoved, until other data is set or the image is deleted.
+pub unsafe fn object_image_data_set(obj: &EvasObject, data: &mut [u32]) {
+    assert!(data.len() >= _image_data_len(obj));
+    evas_object_image_data_set(obj, transmute(data.as_ptr()))
+}
+
+/// Copy 'data' into the image.
+/// Panics if 'data' holds less than 'stride_get / 4' pixels per row
+/// for the image height.
+pub fn object_image_data_copy_set(obj: &EvasObject, data: &[u32]) {
+    assert!(data.len() >= _image_data_len(obj));
+    unsafe { evas_object_image_data_copy_set(obj, transmute(data.as_ptr())) }
+}
+
+impl<'r> ImageData<'r> {
+    /// The pixels, 'stride_get / 4' per row.
+    pub fn as_slice<'a>(&'a self) -> &'a [u32] {
+        self._data
+    }
+}
+
+#[unsafe_destructor]
+impl<'r> Drop for ImageData<'r> {
+    fn drop(&mut self) {
+        let readers = _image_borrow_get(self._obj) - 1;
+        if readers == 0 {
+            unsafe { evas_object_image_data_set(self._obj, transmute(self._data.as_ptr())) }
+        }
+        _image_borrow_set(self._obj, readers);
+    }
+}
+
+impl<'r> ImageDataMut<'r> {
+    /// The pixels, 'stride_get / 4' per row.
+    pub fn as_slice<'a>(&'a self) -> &'a [u32] {
+        &*self._data
+    }
+
+    /// The pixels, 'stride_get / 4' per row, for writing.
+    pub fn as_mut_slice<'a>(&'a mut self) -> &'a mut [u32] {
+        &mut *self._data
+    }
+}
+
+#[unsafe_destructor]
+impl<'r> Drop for ImageDataMut<'r> {
+    fn drop(&mut self) {
+        unsafe { evas_object_image_data_set(self._obj, transmute(self._data.as_ptr())) }
+        _image_borrow_set(self._obj, 0);
+    }
+}
+
+/* Number of pixels of the ARGB8888 data of the image */
+fn _image_data_len(obj: &EvasObject) -> uint {
+    let (_, h) = object_image_size_get(obj);
+    (object_image_stride_get(obj) / 4 * h) as uint
+}
+
+/* The ARGB8888 data of the image, as a slice of '_image_data_len' pixels */
+fn _image_data<'r>(obj: &'r EvasObject) -> Option<&'r [u32]> {
+    _image_data_ptr(obj, false).map(|data| unsafe {
+        transmute(Slice { data: data, len: _image_data_len(obj) })
+    })
+}
+
+fn _image_data_mut<'r>(obj: &'r EvasObject) -> Option<&'r mut [u32]> {
+    _image_data_ptr(obj, true).map(|data| unsafe {
+        transmute(Slice { data: data, len: _image_data_len(obj) })
+    })
+}
+
+fn _image_data_ptr(obj: &EvasObject, for_writing: bool) -> Option<*u32> {
+    if object_image_colorspace_get(obj) != Some(EvasColorspaceArgb8888) { return None }
+    let data = unsafe {
+        evas_object_image_data_get(obj, eseful::from_bool_to_eina(for_writing))
+    };
+    if data.is_null() { None } else { Some(data as *u32) }
+}
+
+/* Borrow state of the image data: the number of readers, or -1 for a
+ * writer. It is kept as object data so that all the references to the
+ * object share it */
+fn _image_borrow_get(obj: &EvasObject) -> int {
+    _IMAGE_BORROW_KEY.with_c_str(|c_key| unsafe {
+        transmute::<*c_void, int>(evas_object_data_get(obj, c_key))
+    })
+}
+
+fn _image_borrow_set(obj: &EvasObject, borrow: int) {
+    _IMAGE_BORROW_KEY.with_c_str(|c_key| unsafe {
+        if borrow == 0 { evas_object_data_del(obj, c_key); }
+        else { evas_object_data_set(obj, c_key, transmute(borrow)) }
+    })
+}
+
+/// Mark the area of the image given as (x, y, width, height) as
+/// changed, to be redrawn.
+pub fn object_image_data_update_add(obj: &EvasObject, g: Geometry) {
+    let (x, y, w, h) = g;
+    unsafe {
+        evas_object_image_data_update_add(obj, x as c_int, y as c_int, w as c_int, h as c_int)
+    }
+}
+
+/// Set whether the image has an alpha channel.
+pub fn object_image_alpha_set(obj: &EvasObject, has_alpha: bool) {
+    unsafe { evas_object_image_alpha_set(obj, eseful::from_bool_to_eina(has_alpha)) }
+}
+
+/// Whether the image has an alpha channel.
+pub fn object_image_alpha_get(obj: &EvasObject) -> bool {
+    unsafe { eseful::from_eina_to_bool(evas_object_image_alpha_get(obj)) }
+}
+
+/// Set the colorspace of the image data.
+pub fn object_image_colorspace_set(obj: &EvasObject, cspace: EvasColorspace) {
+    unsafe { evas_object_image_colorspace_set(obj, cspace as c_uint) }
+}
+
+/// Get the colorspace of the image data.
+/// Returns None for colorspaces unknown to 'EvasColorspace'.
+pub fn object_image_colorspace_get(obj: &EvasObject) -> Option<EvasColorspace> {
+    FromPrimitive::from_u32(unsafe { evas_object_image_colorspace_get(obj) })
+}
+
+/// Set whether the image is scaled with a smooth, slower, algorithm.
+pub fn object_image_smooth_scale_set(obj: &EvasObject, smooth_scale: bool) {
+    unsafe { evas_object_image_smooth_scale_set(obj, eseful::from_bool_to_eina(smooth_scale)) }
+}
+
+/// Whether the image is scaled with a smooth algorithm.
+pub fn object_image_smooth_scale_get(obj: &EvasObject) -> bool {
+    unsafe { eseful::from_eina_to_bool(evas_object_image_smooth_scale_get(obj)) }
+}
+
+/// Set the borders of the image, as (left, right, top, bottom), that are
+/// not scaled when the image is stretched.
+pub fn object_image_border_set(obj: &EvasObject, border: Padding) {
+    let (l, r, t, b) = border;
+    unsafe {
+        evas_object_image_border_set(obj, l as c_int, r as c_int, t as c_int, b as c_int)
+    }
+}
+
+/// Get the borders of the image, as (left, right, top, bottom).
+pub fn object_image_border_get(obj: &EvasObject) -> Padding {
+    let (mut l, mut r, mut t, mut b): (c_int, c_int, c_int, c_int) = (0, 0, 0, 0);
+    unsafe { evas_object_image_border_get(obj, &mut l, &mut r, &mut t, &mut b) }
+    (l as int, r as int, t as int, b as int)
+}
+
+/// Set whether the image object's fill property should track the object's size.
+pub fn object_image_filled_set(obj: &EvasObject, setting: bool) {
+    unsafe {
+        evas_object_image_filled_set(obj, eseful::from_bool_to_eina(setting))
+    }
+}
+
+/// Preload an image object's image data in the background.
+pub fn object_image_preload(obj: &EvasObject, cancel: bool) {
+    unsafe {
+        evas_object_image_preload(obj, eseful::from_bool_to_eina(cancel))
+    }
+}
+
+/// Add (register) a callback function to a given Evas object event.
+/// See 'event::object_event_closure_add' to get typed event information.
+pub fn object_event_callback_add<T>(obj: &EvasObject, cbtype: EvasCallbackType,
+                                    func: EvasObjectEventCb<T>, data: &T) {
+    unsafe {
+        evas_object_event_callback_add(obj, cbtype as c_uint,
+                                       transmute(func), transmute(data))
+    }
+}
+
+pub fn object_smart_callback_add<T>(e: &EvasObject, event: &str,
+                                    cb: EvasSmartCb<T>, data: &Option<T>) {
+    /* Transmute both Data and Callback into the C representation */
+    let c_data: *c_void = unsafe { transmute(data) };
+    let c_cb: CEvasSmartCb = unsafe { transmute(cb) };
+
+    event.with_c_str(|c_event| unsafe {
+        evas_object_smart_callback_add(e, c_event, c_cb, c_data)
+    })
+}
+
+pub fn object_rectangle_add(e: &Evas) -> Box<EvasObject> {
+    unsafe { transmute(evas_object_rectangle_add(e)) }
+}
+
+/// Creates a new line object on the given Evas e canvas.
+pub fn object_line_add(e: &Evas) -> Box<EvasObject> {
+    unsafe { transmute(evas_object_line_add(e)) }
+}
+
+/// Set the end points of the line, in canvas coordinates.
+/// This also moves and resizes the object to the bounding box of the line.
+pub fn object_line_xy_set(obj: &EvasObject, from: Coord, to: Coord) {
+    let ((x1, y1), (x2, y2)) = (from, to);
+    unsafe {
+        evas_object_line_xy_set(obj, x1 as c_int, y1 as c_int, x2 as c_int, y2 as c_int)
+    }
+}
+
+/// Get the end points of the line.
+pub fn object_line_xy_get(obj: &EvasObject) -> (Coord, Coord) {
+    let (mut x1, mut y1, mut x2, mut y2): (c_int, c_int, c_int, c_int) = (0, 0, 0, 0);
+    unsafe { evas_object_line_xy_get(obj, &mut x1, &mut y1, &mut x2, &mut y2) }
+    ((x1 as int, y1 as int), (x2 as int, y2 as int))
+}
+
+/// Creates a new, empty, polygon object on the given Evas e canvas.
+pub fn object_polygon_add(e: &Evas) -> Box<EvasObject> {
+    unsafe { transmute(evas_object_polygon_add(e)) }
+}
+
+/// Add a vertex to the polygon, in canvas coordinates.
+pub fn object_polygon_point_add(obj: &EvasObject, xy: Coord) {
+    let (x, y) = xy;
+    unsafe { evas_object_polygon_point_add(obj, x as c_int, y as c_int) }
+}
+
+/// Add all the given vertices to the polygon, in order.
+pub fn object_polygon_points_add(obj: &EvasObject, points: &[Coord]) {
+    for &xy in points.iter() { object_polygon_point_add(obj, xy) }
+}
+
+/// Remove all the vertices of the polygon.
+pub fn object_polygon_points_clear(obj: &EvasObject) {
+    unsafe { evas_object_polygon_points_clear(obj) }
+}
+
+/// Creates a new polygon object with the given vertices.
+pub fn object_polygon_from_points(e: &Evas, points: &[Coord]) -> Box<EvasObject> {
+    let obj = object_polygon_add(e);
+    object_polygon_points_add(obj, points);
+    obj
+}
+
+/// Draw a polyline through 'points' with line objects, one per segment.
+pub fn object_polyline_add(e: &Evas, points: &[Coord]) -> Vec<Box<EvasObject>> {
+    points.windows(2).map(|segment| {
+        let line = object_line_add(e);
+        object_line_xy_set(line, segment[0], segment[1]);
+        line
+    }).collect()
+}
+
+/// Get the canvas the object lives on.
+pub fn object_evas_get<'r>(obj: &'r EvasObject) -> &'r Evas {
+    unsafe { transmute(evas_object_evas_get(obj)) }
+}
+
+impl<'r> EvasObjects<'r> {
+    fn new(list: *eina::_CEinaList, owned: bool) -> EvasObjects<'r> {
+        let l: *mut eina::_EinaList<'r, EvasObject> = unsafe { transmute(list) };
+        EvasObjects { _list: l, _it: eina::EinaList::new(l), _owned: owned }
+    }
+}
+
+impl<'r> Iterator<&'r EvasObject> for EvasObjects<'r> {
+    fn next(&mut self) -> Option<&'r EvasObject> {
+        self._it.next()
+    }
+}
+
+#[unsafe_destructor]
+impl<'r> Drop for EvasObjects<'r> {
+    fn drop(&mut self) {
+        if self._owned && self._list.is_not_null() { eina::list_free(self._list); }
+    }
+}
+
+#[inline]
+fn _object_option<'r>(obj: *EvasObject) -> Option<&'r EvasObject> {
+    if obj.is_null() { None } else { Some(unsafe { transmute(obj) }) }
+}
+
+/// Clip the object to the area of 'clip'.
+/// Only the part of 'obj' inside the clipper is drawn, and the colour
+/// of the clipper multiplies the colour of the object.
+pub fn object_clip_set(obj: &EvasObject, clip: &EvasObject) {
+    unsafe { evas_object_clip_set(obj, clip) }
+}
+
+/// Stop clipping the object.
+pub fn object_clip_unset(obj: &EvasObject) {
+    unsafe { evas_object_clip_unset(obj) }
+}
+
+/// Get the object clipping 'obj', if any.
+pub fn object_clip_get<'r>(obj: &'r EvasObject) -> Option<&'r EvasObject> {
+    _object_option(unsafe { evas_object_clip_get(obj) })
+}
+
+/// Iterate over the objects clipped by 'obj'.
+pub fn object_clipees_get<'r>(obj: &'r EvasObject) -> EvasObjects<'r> {
+    EvasObjects::new(unsafe { evas_object_clipees_get(obj) }, false)
+}
+
+/// Move the object to layer 'l'.
+/// Objects on higher layers are always stacked above lower ones.
+pub fn object_layer_set(obj: &EvasObject, l: int) {
+    unsafe { evas_object_layer_set(obj, l as c_short) }
+}
+
+/// Get the layer of the object.
+pub fn object_layer_get(obj: &EvasObject) -> int {
+    unsafe { evas_object_layer_get(obj) as int }
+}
+
+/// Raise the object to the top of its layer.
+pub fn object_raise(obj: &EvasObject) {
+    unsafe { evas_object_raise(obj) }
+}
+
+/// Lower the object to the bottom of its layer.
+pub fn object_lower(obj: &EvasObject) {
+    unsafe { evas_object_lower(obj) }
+}
+
+/// Stack 'obj' right above 'above'. Both must be on the same layer.
+pub fn object_stack_above(obj: &EvasObject, above: &EvasObject) {
+    unsafe { evas_object_stack_above(obj, above) }
+}
+
+/// Stack 'obj' right below 'below'. Both must be on the same layer.
+pub fn object_stack_below(obj: &EvasObject, below: &EvasObject) {
+    unsafe { evas_object_stack_below(obj, below) }
+}
+
+/// Get the object stacked right above 'obj', if any.
+pub fn object_above_get<'r>(obj: &'r EvasObject) -> Option<&'r EvasObject> {
+    _object_option(unsafe { evas_object_above_get(obj) })
+}
+
+/// Get the object stacked right below 'obj', if any.
+pub fn object_below_get<'r>(obj: &'r EvasObject) -> Option<&'r EvasObject> {
+    _object_option(unsafe { evas_object_below_get(obj) })
+}
+
+/// Get the topmost object of the canvas.
+pub fn object_top_get<'r>(e: &'r Evas) -> Option<&'r EvasObject> {
+    _object_option(unsafe { evas_object_top_get(e) })
+}
+
+/// Get the lowest object of the canvas.
+pub fn object_bottom_get<'r>(e: &'r Evas) -> Option<&'r EvasObject> {
+    _object_option(unsafe { evas_object_bottom_get(e) })
+}
+
+/// Get the topmost object at the given canvas position.
+pub fn object_top_at_xy_get<'r>(e: &'r Evas, xy: Coord, include_pass_events: bool,
+                                include_hidden: bool) -> Option<&'r EvasObject> {
+    let (x, y) = xy;
+    _object_option(unsafe {
+        evas_object_top_at_xy_get(e, x as c_int, y as c_int,
+                                  eseful::from_bool_to_eina(include_pass_events),
+                                  eseful::from_bool_to_eina(include_hidden))
+    })
+}
+
+/// Iterate over the objects at the given canvas position, from top to bottom.
+pub fn objects_at_xy_get<'r>(e: &'r Evas, xy: Coord, include_pass_events: bool,
+                             include_hidden: bool) -> EvasObjects<'r> {
+    let (x, y) = xy;
+    let list = unsafe {
+        evas_objects_at_xy_get(e, x as c_int, y as c_int,
+                               eseful::from_bool_to_eina(include_pass_events),
+                               eseful::from_bool_to_eina(include_hidden))
+    };
+    EvasObjects::new(list, true)
+}
+
+/// Make the object ignore input events, which go to the objects below it.
+pub fn object_pass_events_set(obj: &EvasObject, pass: bool) {
+    unsafe { evas_object_pass_events_set(obj, eseful::from_bool_to_eina(pass)) }
+}
+
+/// Whether the object passes input events to the objects below it.
+pub fn object_pass_events_get(obj: &EvasObject) -> bool {
+    unsafe { eseful::from_eina_to_bool(evas_object_pass_events_get(obj)) }
+}
+
+/// Make the object handle input events and also repeat them to
+/// the objects below it.
+pub fn object_repeat_events_set(obj: &EvasObject, repeat: bool) {
+    unsafe { evas_object_repeat_events_set(obj, eseful::from_bool_to_eina(repeat)) }
+}
+
+/// Whether the object repeats input events to the objects below it.
+pub fn object_repeat_events_get(obj: &EvasObject) -> bool {
+    unsafe { eseful::from_eina_to_bool(evas_object_repeat_events_get(obj)) }
+}
+
+/// Set whether input events on the object propagate to its smart parent.
+pub fn object_propagate_events_set(obj: &EvasObject, prop: bool) {
+    unsafe { evas_object_propagate_events_set(obj, eseful::from_bool_to_eina(prop)) }
+}
+
+/// Whether input events on the object propagate to its smart parent.
+pub fn object_propagate_events_get(obj: &EvasObject) -> bool {
+    unsafe { eseful::from_eina_to_bool(evas_object_propagate_events_get(obj)) }
+}
+
+/// Feed a mouse move event to the canvas.
+pub fn event_feed_mouse_move(e: &Evas, xy: Coord, timestamp: uint) {
+    let (x, y) = xy;
+    unsafe {
+        evas_event_feed_mouse_move(e, x as c_int, y as c_int,
+                                   timestamp as c_uint, ptr::null())
+    }
+}
+
+/// Feed a mouse button 'b' press event to the canvas.
+pub fn event_feed_mouse_down(e: &Evas, b: int, flags: ButtonFlags, timestamp: uint) {
+    unsafe {
+        evas_event_feed_mouse_down(e, b as c_int, flags, timestamp as c_uint, ptr::null())
+    }
+}
+
+/// Feed a mouse button 'b' release event to the canvas.
+pub fn event_feed_mouse_up(e: &Evas, b: int, flags: ButtonFlags, timestamp: uint) {
+    unsafe {
+        evas_event_feed_mouse_up(e, b as c_int, flags, timestamp as c_uint, ptr::null())
+    }
+}
+
+/// Feed a mouse wheel event to the canvas.
+/// 'direction' is 0 for the vertical wheel and 1 for the horizontal one,
+/// 'z' is negative when scrolling up or left.
+pub fn event_feed_mouse_wheel(e: &Evas, direction: int, z: int, timestamp: uint) {
+    unsafe {
+        evas_event_feed_mouse_wheel(e, direction as c_int, z as c_int,
+                                    timestamp as c_uint, ptr::null())
+    }
+}
+
+/// Feed a key press event to the canvas.
+/// 'keyname' is the name of the physical key (like "Return" or "a"),
+/// 'string' the text it produces, if any.
+pub fn event_feed_key_down(e: &Evas, keyname: &str, string: Option<&str>, timestamp: uint) {
+    _event_feed_key(e, keyname, string, timestamp, evas_event_feed_key_down)
+}
+
+/// Feed a key release event to the canvas.
+pub fn event_feed_key_up(e: &Evas, keyname: &str, string: Option<&str>, timestamp: uint) {
+    _event_feed_key(e, keyname, string, timestamp, evas_event_feed_key_up)
+}
+
+/// Feed a multi-touch press event to the canvas.
+pub fn event_feed_multi_down(e: &Evas, point: &MultiPoint, flags: ButtonFlags,
+                             timestamp: uint) {
+    unsafe {
+        evas_event_feed_multi_down(e, point.device as c_int,
+                                   point.x as c_int, point.y as c_int,
+                                   point.radius, point.radius_x, point.radius_y,
+                                   point.pressure, point.angle, point.fx, point.fy,
+                                   flags, timestamp as c_uint, ptr::null())
+    }
+}
+
+/// Feed a multi-touch release event to the canvas.
+pub fn event_feed_multi_up(e: &Evas, point: &MultiPoint, flags: ButtonFlags,
+                           timestamp: uint) {
+    unsafe {
+        evas_event_feed_multi_up(e, point.device as c_int,
+                                 point.x as c_int, point.y as c_int,
+                                 point.radius, point.radius_x, point.radius_y,
+                                 point.pressure, point.angle, point.fx, point.fy,
+                                 flags, timestamp as c_uint, ptr::null())
+    }
+}
+
+/// Feed a multi-touch move event to the canvas.
+pub fn event_feed_multi_move(e: &Evas, point: &MultiPoint, timestamp: uint) {
+    unsafe {
+        evas_event_feed_multi_move(e, point.device as c_int,
+                                   point.x as c_int, point.y as c_int,
+                                   point.radius, point.radius_x, point.radius_y,
+                                   point.pressure, point.angle, point.fx, point.fy,
+                                   timestamp as c_uint, ptr::null())
+    }
+}
+
+/// Feed a hold event to the canvas.
+/// While on hold, objects should ignore the input events they get.
+pub fn event_feed_hold(e: &Evas, hold: bool, timestamp: uint) {
+    unsafe { evas_event_feed_hold(e, hold as c_int, timestamp as c_uint, ptr::null()) }
+}
+
+fn _event_feed_key(e: &Evas, keyname: &str, string: Option<&str>, timestamp: uint,
+                   feed: unsafe extern "C" fn (*Evas, *c_char, *c_char, *c_char,
+                                                *c_char, c_uint, *c_void)) {
+    let c_string = string.map(|s| s.to_c_str());
+    keyname.with_c_str(|c_keyname| unsafe {
+        let c_str = match c_string { None => ptr::null(), Some(ref s) => s.as_ptr() };
+        feed(e, c_keyname, c_keyname, c_str, c_str, timestamp as c_uint, ptr::null())
+    })
+}
+
+impl MultiPoint {
+    /// A touch point of 'device' at the given position.
+    pub fn new(device: int, xy: Coord) -> MultiPoint {
+        let (x, y) = xy;
+        MultiPoint {
+            device: device, x: x, y: y,
+            radius: 1.0, radius_x: 1.0, radius_y: 1.0,
+            pressure: 1.0, angle: 0.0,
+            fx: x as f64, fy: y as f64
+        }
+    }
+}
+
+impl EventScript {
+    pub fn new() -> EventScript {
+        EventScript { _steps: Vec::new() }
+    }
+
+    /// Move the mouse pointer to the given position.
+    pub fn mouse_move(mut self, xy: Coord) -> EventScript {
+        let (x, y) = xy;
+        self._steps.push(_MouseMove(x, y)); self
+    }
+
+    /// Press the mouse button 'b' (1 is the left button).
+    pub fn mouse_down(mut self, b: int) -> EventScript {
+        self._steps.push(_MouseDown(b, EVAS_BUTTON_NONE)); self
+    }
+
+    /// Release the mouse button 'b'.
+    pub fn mouse_up(mut self, b: int) -> EventScript {
+        self._steps.push(_MouseUp(b, EVAS_BUTTON_NONE)); self
+    }
+
+    /// Turn the vertical mouse wheel by 'z' steps, negative being up.
+    pub fn wheel(mut self, z: int) -> EventScript {
+        self._steps.push(_MouseWheel(0, z)); self
+    }
+
+    /// Press and release the left button at the given position.
+    pub fn click(self, xy: Coord) -> EventScript {
+        self.mouse_move(xy).mouse_down(1).mouse_up(1)
+    }
+
+    /// Double click the left button at the given position.
+    pub fn double_click(mut self, xy: Coord) -> EventScript {
+        self = self.click(xy);
+        self._steps.push(_MouseDown(1, EVAS_BUTTON_DOUBLE_CLICK));
+        self._steps.push(_MouseUp(1, EVAS_BUTTON_DOUBLE_CLICK));
+        self
+    }
+
+    /// Click at the centre of the object.
+    /// Its geometry is read when the step is added.
+    pub fn click_object(self, obj: &EvasObject) -> EventScript {
+        let (x, y, w, h) = object_geometry_get(obj);
+        self.click((x + w / 2, y + h / 2))
+    }
+
+    /// Press the left button at 'from', move to 'to' in 'steps' moves,
+    /// then release the button.
+    pub fn drag(mut self, from: Coord, to: Coord, steps: uint) -> EventScript {
+        let ((x0, y0), (x1, y1)) = (from, to);
+        self = self.mouse_move(from).mouse_down(1);
+        let n = if steps == 0 { 1 } else { steps as int };
+        for i in range(1, n + 1) {
+            self = self.mouse_move((x0 + (x1 - x0) * i / n, y0 + (y1 - y0) * i / n));
+        }
+        self.mouse_up(1)
+    }
+
+    /// Press and release the key named 'keyname' (like "Return" or "BackSpace").
+    pub fn key(mut self, keyname: &str) -> EventScript {
+        self._steps.push(_KeyDown(keyname.to_string(), String::new()));
+        self._steps.push(_KeyUp(keyname.to_string(), String::new()));
+        self
+    }
+
+    /// Type the given text, one key press per character.
+    pub fn type_text(mut self, text: &str) -> EventScript {
+        for c in text.chars() {
+            let string = String::from_char(1, c);
+            let keyname = match c {
+                ' ' => "space".to_string(),
+                '\n' => "Return".to_string(),
+                '\t' => "Tab".to_string(),
+                _ => string.clone()
+            };
+            self._steps.push(_KeyDown(keyname.clone(), string.clone()));
+            self._steps.push(_KeyUp(keyname, string));
+        }
+        self
+    }
+
+    /// Put the canvas on hold or release it.
+    pub fn hold(mut self, hold: bool) -> EventScript {
+        self._steps.push(_Hold(hold)); self
+    }
+
+    /// Feed all the events to the canvas 'e', in order.
+    /// Timestamps start from the main loop time, in milliseconds, so they
+    /// follow those of earlier runs and real input.
+    /// Widgets may only react to the events once the main loop runs.
+    pub fn run(&self, e: &Evas) {
+        let mut timestamp = (ecore::loop_time_get() * 1000.0) as uint;
+        for step in self._steps.iter() {
+            timestamp += 10;
+            match *step {
+                _MouseMove(x, y) => event_feed_mouse_move(e, (x, y), timestamp),
+                _MouseDown(b, flags) => event_feed_mouse_down(e, b, flags, timestamp),
+                _MouseUp(b, flags) => event_feed_mouse_up(e, b, flags, timestamp),
+                _MouseWheel(direction, z) =>
+                    event_feed_mouse_wheel(e, direction, z, timestamp),
+                _KeyDown(ref keyname, ref string) =>
+                    event_feed_key_down(e, keyname.as_slice(), _opt_str(string), timestamp),
+                _KeyUp(ref keyname, ref string) =>
+                    event_feed_key_up(e, keyname.as_slice(), _opt_str(string), timestamp),
+                _Hold(hold) => event_feed_hold(e, hold, timestamp)
+            }
+        }
+    }
+}
+
+/* Empty strings are fed as no string */
+fn _opt_str<'r>(s: &'r String) -> Option<&'r str> {
+    if s.is_empty() { None } else { Some(s.as_slice()) }
+}
@@ -0,0 +1,392 @@
+// Evas smart objects Rust bindings for EFL.
+// Copyright (C) 2014  Luis Araujo <araujoc.luisf@gmail.com>
+
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 2.1 of the License, or (at your option) any later version.
+
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+
+/*
+ * Smart objects written in Rust.
+ *
+ * A smart class is a subclass of the Evas clipped smart class, so its
+ * members are moved, clipped, shown and hidden along with the smart
+ * object. Each smart object owns a value implementing the 'Smart'
+ * trait, which is told about the changes of the object.
+ */
+
+extern crate libc;
+
+use std::kinds::marker;
+use std::mem;
+use std::mem::transmute;
+use std::ptr;
+use std::raw::TraitObject;
+
+use evas::smart::libc::{c_int, c_char, c_void};
+use evas::{Evas, EvasObject};
+use evas::{evas_object_data_set, evas_object_data_get, evas_object_data_del};
+
+
+pub enum EvasSmart {}
+
+/// Behaviour of the smart objects of a class.
+/// The methods are called after the clipped smart class did its job,
+/// and do nothing by default. They are not called while another method
+/// of the same object runs, e.g. for members added from 'add'.
+pub trait Smart {
+    /// The object was created. Members are usually added here.
+    fn add(&mut self, _obj: &EvasObject) {}
+    /// The object is being deleted, along with its members.
+    fn del(&mut self, _obj: &EvasObject) {}
+    /// The object moved to the given position.
+    fn move_to(&mut self, _obj: &EvasObject, _x: int, _y: int) {}
+    /// The object was resized.
+    fn resize(&mut self, _obj: &EvasObject, _w: int, _h: int) {}
+    /// The object was shown.
+    fn show(&mut self, _obj: &EvasObject) {}
+    /// The object was hidden.
+    fn hide(&mut self, _obj: &EvasObject) {}
+    /// The colour of the object changed.
+    fn color_set(&mut self, _obj: &EvasObject, _r: int, _g: int, _b: int, _a: int) {}
+    /// The object is clipped by 'clip'.
+    fn clip_set(&mut self, _obj: &EvasObject, _clip: &EvasObject) {}
+    /// The object is no longer clipped.
+    fn clip_unset(&mut self, _obj: &EvasObject) {}
+    /// The object was marked as changed, lay out its members.
+    fn calculate(&mut self, _obj: &EvasObject) {}
+    /// 'child' became a member of the object.
+    fn member_add(&mut self, _obj: &EvasObject, _child: &EvasObject) {}
+    /// 'child' is no longer a member of the object.
+    fn member_del(&mut self, _obj: &EvasObject, _child: &EvasObject) {}
+}
+
+/// A smart class whose objects own a value of type 'T'.
+/// Like the static classes of C code, classes are never freed.
+pub struct SmartClass<T> {
+    _smart: *EvasSmart,
+    _marker: marker::CovariantType<T>
+}
+
+/* C representation of Evas_Smart_Class */
+struct _CSmartClass {
+    name: *c_char,
+    version: c_int,
+    add: *c_void,
+    del: *c_void,
+    move_: *c_void,
+    resize: *c_void,
+    show: *c_void,
+    hide: *c_void,
+    color_set: *c_void,
+    clip_set: *c_void,
+    clip_unset: *c_void,
+    calculate: *c_void,
+    member_add: *c_void,
+    member_del: *c_void,
+    parent: *_CSmartClass,
+    callbacks: *c_void,
+    interfaces: *c_void,
+    data: *c_void
+}
+
+/* Version of the Evas_Smart_Class structure */
+static EVAS_SMART_CLASS_VERSION: c_int = 4;
+
+/// Borrow of the value of a smart object, from 'SmartClass::data_get'.
+pub struct SmartData<'r, T> {
+    _inst: *mut _SmartInstance,
+    _value: &'r mut T
+}
+
+/* Key of the object data holding the Rust value of a smart object */
+static _SMART_KEY: &'static str = "_rust_efl_smart";
+
+/* The Rust value of a smart object */
+struct _SmartInstance {
+    value: Box<Smart + 'static>,
+    /* A method of the value is running, or it is borrowed */
+    busy: bool,
+    /* The object was deleted while busy, free once released */
+    deleted: bool
+}
+
+
+#[link(name = "evas")]
+extern "C" {
+    fn evas_smart_class_new(sc: *_CSmartClass) -> *EvasSmart;
+    fn evas_object_smart_clipped_smart_set(sc: *mut _CSmartClass);
+    fn evas_object_smart_clipped_class_get() -> *_CSmartClass;
+    fn evas_object_smart_clipped_clipper_get(obj: *EvasObject) -> *EvasObject;
+    fn evas_object_smart_add(e: *Evas, s: *EvasSmart) -> *EvasObject;
+    fn evas_object_smart_smart_get(obj: *EvasObject) -> *EvasSmart;
+    fn evas_object_smart_member_add(obj: *EvasObject, smart_obj: *EvasObject);
+    fn evas_object_smart_member_del(obj: *EvasObject);
+    fn evas_object_smart_parent_get(obj: *EvasObject) -> *EvasObject;
+    fn evas_object_smart_changed(obj: *EvasObject);
+    fn evas_object_smart_callback_call(obj: *EvasObject, event: *c_char, event_info: *c_void);
+}
+
+
+/// Create a new smart class named 'name', for objects owning a 'T'.
+pub fn smart_class_new<T: Smart + 'static>(name: &str) -> SmartClass<T> {
+    unsafe {
+        /* Evas keeps pointers to the name and the class */
+        let c_name = name.to_c_str();
+        let sc: *mut _CSmartClass = transmute(box _CSmartClass {
+            name: c_name.as_ptr(),
+            version: EVAS_SMART_CLASS_VERSION,
+            add: ptr::null(), del: ptr::null(), move_: ptr::null(),
+            resize: ptr::null(), show: ptr::null(), hide: ptr::null(),
+            color_set: ptr::null(), clip_set: ptr::null(), clip_unset: ptr::null(),
+            calculate: ptr::null(), member_add: ptr::null(), member_del: ptr::null(),
+            parent: ptr::null(), callbacks: ptr::null(), interfaces: ptr::null(),
+            data: ptr::null()
+        });
+        mem::forget(c_name);
+
+        evas_object_smart_clipped_smart_set(sc);
+        (*sc).parent = evas_object_smart_clipped_class_get();
+        (*sc).del = transmute(_smart_del);
+        (*sc).move_ = transmute(_smart_move);
+        (*sc).resize = transmute(_smart_resize);
+        (*sc).show = transmute(_smart_show);
+        (*sc).hide = transmute(_smart_hide);
+        (*sc).color_set = transmute(_smart_color_set);
+        (*sc).clip_set = transmute(_smart_clip_set);
+        (*sc).clip_unset = transmute(_smart_clip_unset);
+        (*sc).calculate = transmute(_smart_calculate);
+        (*sc).member_add = transmute(_smart_member_add);
+        (*sc).member_del = transmute(_smart_member_del);
+
+        SmartClass {
+            _smart: evas_smart_class_new(transmute(sc)),
+            _marker: marker::CovariantType
+        }
+    }
+}
+
+impl<T: Smart + 'static> SmartClass<T> {
+    /// Add a smart object of this class to the canvas, owning 'data'.
+    /// The 'add' method of 'data' is called before returning.
+    pub fn object_add(&self, e: &Evas, data: T) -> Box<EvasObject> {
+        unsafe {
+            let obj = evas_object_smart_add(e, self._smart);
+            let inst: *mut _SmartInstance = transmute(box _SmartInstance {
+                value: box data as Box<Smart + 'static>,
+                busy: false,
+                deleted: false
+            });
+            _SMART_KEY.with_c_str(|c_key| evas_object_data_set(obj, c_key, transmute(inst)));
+            _with_instance(obj, |value, o| value.add(o));
+            transmute(obj)
+        }
+    }
+
+    /// Borrow the value owned by a smart object of this class.
+    /// Returns None if the object is of another class, or if the value
+    /// is already borrowed: by another guard or by a running method.
+    /// The methods of the value are not called while the guard lives.
+    pub fn data_get<'r>(&self, obj: &'r EvasObject) -> Option<SmartData<'r, T>> {
+        unsafe {
+            if evas_object_smart_smart_get(obj) != self._smart { return None }
+            let inst = _instance_get(obj);
+            if inst.is_null() || (*inst).busy { return None }
+            (*inst).busy = true;
+            let raw: TraitObject = transmute(&mut *(*inst).value);
+            Some(SmartData { _inst: inst, _value: transmute(raw.data) })
+        }
+    }
+}
+
+impl<'r, T> Deref<T> for SmartData<'r, T> {
+    fn deref<'a>(&'a self) -> &'a T {
+        &*self._value
+    }
+}
+
+impl<'r, T> DerefMut<T> for SmartData<'r, T> {
+    fn deref_mut<'a>(&'a mut self) -> &'a mut T {
+        &mut *self._value
+    }
+}
+
+#[unsafe_destructor]
+impl<'r, T> Drop for SmartData<'r, T> {
+    fn drop(&mut self) {
+        unsafe { _instance_release(self._inst) }
+    }
+}
+
+/// Make 'obj' a member of the smart object 'smart_obj'.
+pub fn object_smart_member_add(obj: &EvasObject, smart_obj: &EvasObject) {
+    unsafe { evas_object_smart_member_add(obj, smart_obj) }
+}
+
+/// Remove 'obj' from the members of its smart object.
+pub fn object_smart_member_del(obj: &EvasObject) {
+    unsafe { evas_object_smart_member_del(obj) }
+}
+
+/// Get the smart object 'obj' is a member of.
+pub fn object_smart_parent_get<'r>(obj: &'r EvasObject) -> Option<&'r EvasObject> {
+    unsafe {
+        let parent = evas_object_smart_parent_get(obj);
+        if parent.is_null() { None } else { Some(transmute(parent)) }
+    }
+}
+
+/// Mark the smart object as changed, so that its 'calculate'
+/// method is called before the next render.
+pub fn object_smart_changed(obj: &EvasObject) {
+    unsafe { evas_object_smart_changed(obj) }
+}
+
+/// Get the object clipping the members of a smart object.
+pub fn object_smart_clipper_get<'r>(obj: &'r EvasObject) -> &'r EvasObject {
+    unsafe { transmute(evas_object_smart_clipped_clipper_get(obj)) }
+}
+
+/// Call the smart callbacks registered for 'event' on the object,
+/// e.g. with 'evas::object_smart_callback_add'.
+pub fn object_smart_callback_call(obj: &EvasObject, event: &str) {
+    event.with_c_str(|c_event| unsafe {
+        evas_object_smart_callback_call(obj, c_event, ptr::null())
+    })
+}
+
+unsafe fn _instance_get(obj: *EvasObject) -> *mut _SmartInstance {
+    transmute(_SMART_KEY.with_c_str(|c_key| evas_object_data_get(obj, c_key)))
+}
+
+/* Run 'func' with the Rust value of the object, if it has one yet
+ * and none of its methods is running */
+fn _with_instance(obj: *EvasObject, func: |&mut Smart, &EvasObject|) {
+    unsafe {
+        let inst = _instance_get(obj);
+        if inst.is_null() || (*inst).busy { return }
+        (*inst).busy = true;
+        func(&mut *(*inst).value, &*obj);
+        _instance_release(inst);
+    }
+}
+
+/* End the use of the value, freeing it if its object is gone meanwhile */
+unsafe fn _instance_release(inst: *mut _SmartInstance) {
+    (*inst).busy = false;
+    if (*inst).deleted {
+        drop(transmute::<*mut _SmartInstance, Box<_SmartInstance>>(inst));
+    }
+}
+
+fn _parent() -> &'static _CSmartClass {
+    unsafe { transmute(evas_object_smart_clipped_class_get()) }
+}
+
+extern "C" fn _smart_del(o: *EvasObject) {
+    unsafe {
+        _with_instance(o, |value, obj| value.del(obj));
+        let inst = _instance_get(o);
+        if inst.is_not_null() {
+            _SMART_KEY.with_c_str(|c_key| evas_object_data_del(o, c_key));
+            if (*inst).busy { (*inst).deleted = true }
+            else { drop(transmute::<*mut _SmartInstance, Box<_SmartInstance>>(inst)) }
+        }
+        if _parent().del.is_not_null() {
+            let f: extern "C" fn (*EvasObject) = transmute(_parent().del);
+            f(o)
+        }
+    }
+}
+
+extern "C" fn _smart_move(o: *EvasObject, x: c_int, y: c_int) {
+    unsafe {
+        if _parent().move_.is_not_null() {
+            let f: extern "C" fn (*EvasObject, c_int, c_int) = transmute(_parent().move_);
+            f(o, x, y)
+        }
+    }
+    _with_instance(o, |value, obj| value.move_to(obj, x as int, y as int))
+}
+
+extern "C" fn _smart_resize(o: *EvasObject, w: c_int, h: c_int) {
+    unsafe {
+        if _parent().resize.is_not_null() {
+            let f: extern "C" fn (*EvasObject, c_int, c_int) = transmute(_parent().resize);
+            f(o, w, h)
+        }
+    }
+    _with_instance(o, |value, obj| value.resize(obj, w as int, h as int))
+}
+
+extern "C" fn _smart_show(o: *EvasObject) {
+    _parent_call(o, _parent().show);
+    _with_instance(o, |value, obj| value.show(obj))
+}
+
+extern "C" fn _smart_hide(o: *EvasObject) {
+    _parent_call(o, _parent().hide);
+    _with_instance(o, |value, obj| value.hide(obj))
+}
+
+extern "C" fn _smart_color_set(o: *EvasObject, r: c_int, g: c_int, b: c_int, a: c_int) {
+    unsafe {
+        if _parent().color_set.is_not_null() {
+            let f: extern "C" fn (*EvasObject, c_int, c_int, c_int, c_int) =
+                transmute(_parent().color_set);
+            f(o, r, g, b, a)
+        }
+    }
+    _with_instance(o, |value, obj| value.color_set(obj, r as int, g as int, b as int, a as int))
+}
+
+extern "C" fn _smart_clip_set(o: *EvasObject, clip: *EvasObject) {
+    _parent_call2(o, clip, _parent().clip_set);
+    _with_instance(o, |value, obj| value.clip_set(obj, unsafe { &*clip }))
+}
+
+extern "C" fn _smart_clip_unset(o: *EvasObject) {
+    _parent_call(o, _parent().clip_unset);
+    _with_instance(o, |value, obj| value.clip_unset(obj))
+}
+
+extern "C" fn _smart_calculate(o: *EvasObject) {
+    _parent_call(o, _parent().calculate);
+    _with_instance(o, |value, obj| value.calculate(obj))
+}
+
+extern "C" fn _smart_member_add(o: *EvasObject, child: *EvasObject) {
+    _parent_call2(o, child, _parent().member_add);
+    _with_instance(o, |value, obj| value.member_add(obj, unsafe { &*child }))
+}
+
+extern "C" fn _smart_member_del(o: *EvasObject, child: *EvasObject) {
+    _parent_call2(o, child, _parent().member_del);
+    _with_instance(o, |value, obj| value.member_del(obj, unsafe { &*child }))
+}
+
+/* Call a hook of the clipped smart class, if it has one */
+fn _parent_call(o: *EvasObject, hook: *c_void) {
+    if hook.is_null() { return }
+    unsafe {
+        let f: extern "C" fn (*EvasObject) = transmute(hook);
+        f(o)
+    }
+}
+
+fn _parent_call2(o: *EvasObject, other: *EvasObject, hook: *c_void) {
+    if hook.is_null() { return }
+    unsafe {
+        let f: extern "C" fn (*EvasObject, *EvasObject) = transmute(hook);
+        f(o, other)
+    }
+}
@@ -0,0 +1,583 @@
+// Evas text and textblock Rust bindings for EFL.
+// Copyright (C) 2014  Luis Araujo <araujoc.luisf@gmail.com>
+
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 2.1 of the License, or (at your option) any later version.
+
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+
+/*
+ * Text objects, showing a single line of text with a font and an
+ * effect, and textblock objects, showing formatted multi-line text
+ * described with markup and edited through cursors.
+ */
+
+extern crate libc;
+
+use std::mem::transmute;
+use std::ptr;
+
+use evas::text::libc::{c_int, c_uint, c_char, free};
+use evas::{Evas, EvasObject, Coord, Color, Geometry};
+use eina;
+use eseful::{from_eina_to_bool, from_c_to_string};
+
+
+pub enum EvasTextblockStyle {}
+pub enum EvasTextblockCursor {}
+
+/// Effect used to draw a text object.
+/// The effects with a shadow can be or'ed with a EVAS_TEXT_STYLE_SHADOW_DIRECTION_*.
+pub type TextStyleType = c_uint;
+
+/// Plain, standard text
+pub static EVAS_TEXT_STYLE_PLAIN: TextStyleType = 0;
+/// Text with shadow underneath
+pub static EVAS_TEXT_STYLE_SHADOW: TextStyleType = 1;
+/// Text with an outline
+pub static EVAS_TEXT_STYLE_OUTLINE: TextStyleType = 2;
+/// Text with a soft outline
+pub static EVAS_TEXT_STYLE_SOFT_OUTLINE: TextStyleType = 3;
+/// Text with a glow effect
+pub static EVAS_TEXT_STYLE_GLOW: TextStyleType = 4;
+/// Text with both outline and shadow effects
+pub static EVAS_TEXT_STYLE_OUTLINE_SHADOW: TextStyleType = 5;
+/// Text with (far) shadow underneath
+pub static EVAS_TEXT_STYLE_FAR_SHADOW: TextStyleType = 6;
+/// Text with outline and soft shadow effects combined
+pub static EVAS_TEXT_STYLE_OUTLINE_SOFT_SHADOW: TextStyleType = 7;
+/// Text with (soft) shadow underneath
+pub static EVAS_TEXT_STYLE_SOFT_SHADOW: TextStyleType = 8;
+/// Text with (far soft) shadow underneath
+pub static EVAS_TEXT_STYLE_FAR_SOFT_SHADOW: TextStyleType = 9;
+
+/// Shadow towards the bottom right
+pub static EVAS_TEXT_STYLE_SHADOW_DIRECTION_BOTTOM_RIGHT: TextStyleType = 0 << 4;
+/// Shadow towards the bottom
+pub static EVAS_TEXT_STYLE_SHADOW_DIRECTION_BOTTOM: TextStyleType = 1 << 4;
+/// Shadow towards the bottom left
+pub static EVAS_TEXT_STYLE_SHADOW_DIRECTION_BOTTOM_LEFT: TextStyleType = 2 << 4;
+/// Shadow towards the left
+pub static EVAS_TEXT_STYLE_SHADOW_DIRECTION_LEFT: TextStyleType = 3 << 4;
+/// Shadow towards the top left
+pub static EVAS_TEXT_STYLE_SHADOW_DIRECTION_TOP_LEFT: TextStyleType = 4 << 4;
+/// Shadow towards the top
+pub static EVAS_TEXT_STYLE_SHADOW_DIRECTION_TOP: TextStyleType = 5 << 4;
+/// Shadow towards the top right
+pub static EVAS_TEXT_STYLE_SHADOW_DIRECTION_TOP_RIGHT: TextStyleType = 6 << 4;
+/// Shadow towards the right
+pub static EVAS_TEXT_STYLE_SHADOW_DIRECTION_RIGHT: TextStyleType = 7 << 4;
+
+/// What the geometry of a textblock cursor describes.
+pub enum TextblockCursorType {
+    /// The character under the cursor
+    EvasTextblockCursorUnder,
+    /// The position before the character under the cursor
+    EvasTextblockCursorBefore
+}
+
+/// Owned textblock style, holding the markup describing the formats
+/// of a textblock.
+/// Evas keeps it alive while textblocks still use it.
+pub struct TextblockStyle {
+    _ts: *EvasTextblockStyle
+}
+
+/// A position in the text of a textblock.
+/// Cursors created with 'new' are freed on drop, while the main cursor
+/// of the textblock belongs to it.
+pub struct TextblockCursor<'r> {
+    _cur: *EvasTextblockCursor,
+    _owned: bool,
+    _obj: &'r EvasObject
+}
+
+
+#[link(name = "evas")]
+extern "C" {
+    fn evas_object_text_add(e: *Evas) -> *EvasObject;
+    fn evas_object_text_font_set(obj: *EvasObject, font: *c_char, size: c_int);
+    fn evas_object_text_font_get(obj: *EvasObject, font: *mut *c_char, size: *mut c_int);
+    fn evas_object_text_text_set(obj: *EvasObject, text: *c_char);
+    fn evas_object_text_text_get(obj: *EvasObject) -> *c_char;
+    fn evas_object_text_style_set(obj: *EvasObject, style: c_uint);
+    fn evas_object_text_style_get(obj: *EvasObject) -> c_uint;
+    fn evas_object_text_shadow_color_set(obj: *EvasObject,
+                                         r: c_int, g: c_int, b: c_int, a: c_int);
+    fn evas_object_text_shadow_color_get(obj: *EvasObject, r: *mut c_int, g: *mut c_int,
+                                         b: *mut c_int, a: *mut c_int);
+    fn evas_object_text_outline_color_set(obj: *EvasObject,
+                                          r: c_int, g: c_int, b: c_int, a: c_int);
+    fn evas_object_text_outline_color_get(obj: *EvasObject, r: *mut c_int, g: *mut c_int,
+                                          b: *mut c_int, a: *mut c_int);
+    fn evas_object_text_glow_color_set(obj: *EvasObject,
+                                       r: c_int, g: c_int, b: c_int, a: c_int);
+    fn evas_object_text_glow_color_get(obj: *EvasObject, r: *mut c_int, g: *mut c_int,
+                                       b: *mut c_int, a: *mut c_int);
+    fn evas_object_text_ascent_get(obj: *EvasObject) -> c_int;
+    fn evas_object_text_descent_get(obj: *EvasObject) -> c_int;
+    fn evas_object_text_max_ascent_get(obj: *EvasObject) -> c_int;
+    fn evas_object_text_max_descent_get(obj: *EvasObject) -> c_int;
+    fn evas_object_text_horiz_advance_get(obj: *EvasObject) -> c_int;
+    fn evas_object_text_vert_advance_get(obj: *EvasObject) -> c_int;
+    fn evas_object_text_inset_get(obj: *EvasObject) -> c_int;
+    fn evas_object_text_char_pos_get(obj: *EvasObject, pos: c_int,
+                                     cx: *mut c_int, cy: *mut c_int,
+                                     cw: *mut c_int, ch: *mut c_int) -> eina::EinaBool;
+    fn evas_textblock_style_new() -> *EvasTextblockStyle;
+    fn evas_textblock_style_free(ts: *EvasTextblockStyle);
+    fn evas_textblock_style_set(ts: *EvasTextblockStyle, text: *c_char);
+    fn evas_textblock_style_get(ts: *EvasTextblockStyle) -> *c_char;
+    fn evas_object_textblock_add(e: *Evas) -> *EvasObject;
+    fn evas_object_textblock_style_set(obj: *EvasObject, ts: *EvasTextblockStyle);
+    fn evas_object_textblock_text_markup_set(obj: *EvasObject, text: *c_char);
+    fn evas_object_textblock_text_markup_get(obj: *EvasObject) -> *c_char;
+    fn evas_object_textblock_clear(obj: *EvasObject);
+    fn evas_object_textblock_size_formatted_get(obj: *EvasObject,
+                                                w: *mut c_int, h: *mut c_int);
+    fn evas_object_textblock_size_native_get(obj: *EvasObject,
+                                             w: *mut c_int, h: *mut c_int);
+    fn evas_object_textblock_cursor_get(obj: *EvasObject) -> *EvasTextblockCursor;
+    fn evas_object_textblock_cursor_new(obj: *EvasObject) -> *EvasTextblockCursor;
+    fn evas_textblock_cursor_free(cur: *EvasTextblockCursor);
+    fn evas_textblock_cursor_pos_get(cur: *EvasTextblockCursor) -> c_int;
+    fn evas_textblock_cursor_pos_set(cur: *EvasTextblockCursor, pos: c_int);
+    fn evas_textblock_cursor_line_set(cur: *EvasTextblockCursor, line: c_int) -> eina::EinaBool;
+    fn evas_textblock_cursor_char_next(cur: *EvasTextblockCursor) -> eina::EinaBool;
+    fn evas_textblock_cursor_char_prev(cur: *EvasTextblockCursor) -> eina::EinaBool;
+    fn evas_textblock_cursor_word_start(cur: *EvasTextblockCursor) -> eina::EinaBool;
+    fn evas_textblock_cursor_word_end(cur: *EvasTextblockCursor) -> eina::EinaBool;
+    fn evas_textblock_cursor_line_char_first(cur: *EvasTextblockCursor);
+    fn evas_textblock_cursor_line_char_last(cur: *EvasTextblockCursor);
+    fn evas_textblock_cursor_paragraph_first(cur: *EvasTextblockCursor);
+    fn evas_textblock_cursor_paragraph_last(cur: *EvasTextblockCursor);
+    fn evas_textblock_cursor_paragraph_next(cur: *EvasTextblockCursor) -> eina::EinaBool;
+    fn evas_textblock_cursor_paragraph_prev(cur: *EvasTextblockCursor) -> eina::EinaBool;
+    fn evas_textblock_cursor_text_append(cur: *EvasTextblockCursor, text: *c_char) -> c_int;
+    fn evas_textblock_cursor_text_prepend(cur: *EvasTextblockCursor, text: *c_char) -> c_int;
+    fn evas_textblock_cursor_format_append(cur: *EvasTextblockCursor,
+                                           format: *c_char) -> eina::EinaBool;
+    fn evas_textblock_cursor_char_delete(cur: *EvasTextblockCursor);
+    fn evas_textblock_cursor_content_get(cur: *EvasTextblockCursor) -> *c_char;
+    fn evas_textblock_cursor_paragraph_text_get(cur: *EvasTextblockCursor) -> *c_char;
+    fn evas_textblock_cursor_geometry_get(cur: *EvasTextblockCursor,
+                                          cx: *mut c_int, cy: *mut c_int,
+                                          cw: *mut c_int, ch: *mut c_int,
+                                          dir: *mut c_uint, ctype: c_uint) -> c_int;
+    fn evas_textblock_cursor_char_geometry_get(cur: *EvasTextblockCursor,
+                                               cx: *mut c_int, cy: *mut c_int,
+                                               cw: *mut c_int, ch: *mut c_int) -> c_int;
+    fn evas_textblock_cursor_char_coord_set(cur: *EvasTextblockCursor,
+                                            x: c_int, y: c_int) -> eina::EinaBool;
+    fn evas_textblock_cursor_compare(cur1: *EvasTextblockCursor,
+                                     cur2: *EvasTextblockCursor) -> c_int;
+    fn evas_textblock_cursor_copy(cur: *EvasTextblockCursor, cur_dest: *EvasTextblockCursor);
+}
+
+
+/// Add a text object to the canvas.
+pub fn object_text_add(e: &Evas) -> Box<EvasObject> {
+    unsafe { transmute(evas_object_text_add(e)) }
+}
+
+/// Set the font (like "Sans" or "DejaVu:style=Bold") and size of a text object.
+pub fn text_font_set(obj: &EvasObject, font: &str, size: int) {
+    font.with_c_str(|c_font| unsafe {
+        evas_object_text_font_set(obj, c_font, size as c_int)
+    })
+}
+
+/// Get the font and size of a text object.
+pub fn text_font_get(obj: &EvasObject) -> (String, int) {
+    let mut font: *c_char = ptr::null();
+    let mut size: c_int = 0;
+    unsafe {
+        evas_object_text_font_get(obj, &mut font, &mut size);
+        (from_c_to_string(font).unwrap_or(String::new()), size as int)
+    }
+}
+
+/// Set the text shown by a text object.
+pub fn text_text_set(obj: &EvasObject, text: &str) {
+    text.with_c_str(|c_text| unsafe { evas_object_text_text_set(obj, c_text) })
+}
+
+/// Get the text shown by a text object.
+pub fn text_text_get(obj: &EvasObject) -> String {
+    unsafe { from_c_to_string(evas_object_text_text_get(obj)).unwrap_or(String::new()) }
+}
+
+/// Set the effect used to draw a text object.
+pub fn text_style_set(obj: &EvasObject, style: TextStyleType) {
+    unsafe { evas_object_text_style_set(obj, style) }
+}
+
+/// Get the effect used to draw a text object.
+pub fn text_style_get(obj: &EvasObject) -> TextStyleType {
+    unsafe { evas_object_text_style_get(obj) }
+}
+
+/// Set the shadow colour of a text object.
+pub fn text_shadow_color_set(obj: &EvasObject, color: Color) {
+    let (r, g, b, a) = color;
+    unsafe {
+        evas_object_text_shadow_color_set(obj, r as c_int, g as c_int, b as c_int, a as c_int)
+    }
+}
+
+/// Get the shadow colour of a text object.
+pub fn text_shadow_color_get(obj: &EvasObject) -> Color {
+    _color_get(obj, evas_object_text_shadow_color_get)
+}
+
+/// Set the outline colour of a text object.
+pub fn text_outline_color_set(obj: &EvasObject, color: Color) {
+    let (r, g, b, a) = color;
+    unsafe {
+        evas_object_text_outline_color_set(obj, r as c_int, g as c_int, b as c_int, a as c_int)
+    }
+}
+
+/// Get the outline colour of a text object.
+pub fn text_outline_color_get(obj: &EvasObject) -> Color {
+    _color_get(obj, evas_object_text_outline_color_get)
+}
+
+/// Set the glow colour of a text object.
+pub fn text_glow_color_set(obj: &EvasObject, color: Color) {
+    let (r, g, b, a) = color;
+    unsafe {
+        evas_object_text_glow_color_set(obj, r as c_int, g as c_int, b as c_int, a as c_int)
+    }
+}
+
+/// Get the glow colour of a text object.
+pub fn text_glow_color_get(obj: &EvasObject) -> Color {
+    _color_get(obj, evas_object_text_glow_color_get)
+}
+
+/// Get the ascent of the font of a text object.
+pub fn text_ascent_get(obj: &EvasObject) -> int {
+    unsafe { evas_object_text_ascent_get(obj) as int }
+}
+
+/// Get the descent of the font of a text object.
+pub fn text_descent_get(obj: &EvasObject) -> int {
+    unsafe { evas_object_text_descent_get(obj) as int }
+}
+
+/// Get the maximum ascent of the font of a text object.
+pub fn text_max_ascent_get(obj: &EvasObject) -> int {
+    unsafe { evas_object_text_max_ascent_get(obj) as int }
+}
+
+/// Get the maximum descent of the font of a text object.
+pub fn text_max_descent_get(obj: &EvasObject) -> int {
+    unsafe { evas_object_text_max_descent_get(obj) as int }
+}
+
+/// Get the horizontal advance of the text, the width it takes
+/// when followed by more text.
+pub fn text_horiz_advance_get(obj: &EvasObject) -> int {
+    unsafe { evas_object_text_horiz_advance_get(obj) as int }
+}
+
+/// Get the vertical advance of the text.
+pub fn text_vert_advance_get(obj: &EvasObject) -> int {
+    unsafe { evas_object_text_vert_advance_get(obj) as int }
+}
+
+/// Get the inset of the text, the space before its first character.
+pub fn text_inset_get(obj: &EvasObject) -> int {
+    unsafe { evas_object_text_inset_get(obj) as int }
+}
+
+/// Get the geometry of the character at position 'pos' of a text object.
+pub fn text_char_pos_get(obj: &EvasObject, pos: int) -> Option<Geometry> {
+    let (mut x, mut y, mut w, mut h): (c_int, c_int, c_int, c_int) = (0, 0, 0, 0);
+    unsafe {
+        match from_eina_to_bool(evas_object_text_char_pos_get(obj, pos as c_int, &mut x,
+                                                              &mut y, &mut w, &mut h)) {
+            true => Some((x as int, y as int, w as int, h as int)),
+            false => None
+        }
+    }
+}
+
+/// Add a textblock object to the canvas.
+pub fn object_textblock_add(e: &Evas) -> Box<EvasObject> {
+    unsafe { transmute(evas_object_textblock_add(e)) }
+}
+
+/// Set the style of a textblock.
+pub fn textblock_style_set(obj: &EvasObject, style: &TextblockStyle) {
+    unsafe { evas_object_textblock_style_set(obj, style._ts) }
+}
+
+/// Set the markup text of a textblock, like "Some <b>bold</b> text".
+pub fn textblock_text_markup_set(obj: &EvasObject, text: &str) {
+    text.with_c_str(|c_text| unsafe {
+        evas_object_textblock_text_markup_set(obj, c_text)
+    })
+}
+
+/// Get the markup text of a textblock.
+pub fn textblock_text_markup_get(obj: &EvasObject) -> String {
+    unsafe {
+        from_c_to_string(evas_object_textblock_text_markup_get(obj)).unwrap_or(String::new())
+    }
+}
+
+/// Remove all the text of a textblock.
+pub fn textblock_clear(obj: &EvasObject) {
+    unsafe { evas_object_textblock_clear(obj) }
+}
+
+/// Get the size of the text of a textblock once formatted
+/// to fit its width.
+pub fn textblock_size_formatted_get(obj: &EvasObject) -> Coord {
+    let (mut w, mut h): (c_int, c_int) = (0, 0);
+    unsafe { evas_object_textblock_size_formatted_get(obj, &mut w, &mut h) }
+    (w as int, h as int)
+}
+
+/// Get the size the text of a textblock takes without wrapping.
+pub fn textblock_size_native_get(obj: &EvasObject) -> Coord {
+    let (mut w, mut h): (c_int, c_int) = (0, 0);
+    unsafe { evas_object_textblock_size_native_get(obj, &mut w, &mut h) }
+    (w as int, h as int)
+}
+
+/// Get the main cursor of a textblock.
+pub fn textblock_cursor_get<'r>(obj: &'r EvasObject) -> TextblockCursor<'r> {
+    TextblockCursor {
+        _cur: unsafe { evas_object_textblock_cursor_get(obj) },
+        _owned: false,
+        _obj: obj
+    }
+}
+
+impl TextblockStyle {
+    /// Create a style from its markup, like
+    /// "DEFAULT='font=Sans font_size=12 color=#000'".
+    pub fn new(markup: &str) -> TextblockStyle {
+        let style = TextblockStyle { _ts: unsafe { evas_textblock_style_new() } };
+        style.set(markup);
+        style
+    }
+
+    /// Set the markup of the style.
+    pub fn set(&self, markup: &str) {
+        markup.with_c_str(|c_markup| unsafe {
+            evas_textblock_style_set(self._ts, c_markup)
+        })
+    }
+
+    /// Get the markup of the style.
+    pub fn get(&self) -> String {
+        unsafe {
+            from_c_to_string(evas_textblock_style_get(self._ts)).unwrap_or(String::new())
+        }
+    }
+}
+
+impl Drop for TextblockStyle {
+    fn drop(&mut self) {
+        unsafe { evas_textblock_style_free(self._ts) }
+    }
+}
+
+impl<'r> TextblockCursor<'r> {
+    /// Create a new cursor on the textblock, at the start of its text.
+    pub fn new(obj: &'r EvasObject) -> TextblockCursor<'r> {
+        TextblockCursor {
+            _cur: unsafe { evas_object_textblock_cursor_new(obj) },
+            _owned: true,
+            _obj: obj
+        }
+    }
+
+    /// Get the textblock of the cursor.
+    pub fn object_get(&self) -> &'r EvasObject {
+        self._obj
+    }
+
+    /// Get the position of the cursor, in characters.
+    pub fn pos_get(&self) -> int {
+        unsafe { evas_textblock_cursor_pos_get(self._cur) as int }
+    }
+
+    /// Set the position of the cursor, in characters.
+    pub fn pos_set(&self, pos: int) {
+        unsafe { evas_textblock_cursor_pos_set(self._cur, pos as c_int) }
+    }
+
+    /// Move the cursor to the start of the given line.
+    pub fn line_set(&self, line: int) -> bool {
+        unsafe { from_eina_to_bool(evas_textblock_cursor_line_set(self._cur, line as c_int)) }
+    }
+
+    /// Move to the next character. Returns false at the end of the text.
+    pub fn char_next(&self) -> bool {
+        unsafe { from_eina_to_bool(evas_textblock_cursor_char_next(self._cur)) }
+    }
+
+    /// Move to the previous character. Returns false at the start of the text.
+    pub fn char_prev(&self) -> bool {
+        unsafe { from_eina_to_bool(evas_textblock_cursor_char_prev(self._cur)) }
+    }
+
+    /// Move to the start of the current word.
+    pub fn word_start(&self) -> bool {
+        unsafe { from_eina_to_bool(evas_textblock_cursor_word_start(self._cur)) }
+    }
+
+    /// Move to the end of the current word.
+    pub fn word_end(&self) -> bool {
+        unsafe { from_eina_to_bool(evas_textblock_cursor_word_end(self._cur)) }
+    }
+
+    /// Move to the first character of the current line.
+    pub fn line_char_first(&self) {
+        unsafe { evas_textblock_cursor_line_char_first(self._cur) }
+    }
+
+    /// Move to the last character of the current line.
+    pub fn line_char_last(&self) {
+        unsafe { evas_textblock_cursor_line_char_last(self._cur) }
+    }
+
+    /// Move to the start of the text.
+    pub fn paragraph_first(&self) {
+        unsafe { evas_textblock_cursor_paragraph_first(self._cur) }
+    }
+
+    /// Move to the end of the text.
+    pub fn paragraph_last(&self) {
+        unsafe { evas_textblock_cursor_paragraph_last(self._cur) }
+    }
+
+    /// Move to the start of the next paragraph.
+    pub fn paragraph_next(&self) -> bool {
+        unsafe { from_eina_to_bool(evas_textblock_cursor_paragraph_next(self._cur)) }
+    }
+
+    /// Move to the start of the previous paragraph.
+    pub fn paragraph_prev(&self) -> bool {
+        unsafe { from_eina_to_bool(evas_textblock_cursor_paragraph_prev(self._cur)) }
+    }
+
+    /// Insert plain text after the cursor, moving the cursor after it.
+    /// Returns the number of characters added.
+    pub fn text_append(&self, text: &str) -> int {
+        text.with_c_str(|c_text| unsafe {
+            evas_textblock_cursor_text_append(self._cur, c_text) as int
+        })
+    }
+
+    /// Insert plain text before the cursor.
+    /// Returns the number of characters added.
+    pub fn text_prepend(&self, text: &str) -> int {
+        text.with_c_str(|c_text| unsafe {
+            evas_textblock_cursor_text_prepend(self._cur, c_text) as int
+        })
+    }
+
+    /// Insert a format, like "+ color=#f00" or "br", after the cursor.
+    pub fn format_append(&self, format: &str) -> bool {
+        format.with_c_str(|c_format| unsafe {
+            from_eina_to_bool(evas_textblock_cursor_format_append(self._cur, c_format))
+        })
+    }
+
+    /// Delete the character under the cursor.
+    pub fn char_delete(&self) {
+        unsafe { evas_textblock_cursor_char_delete(self._cur) }
+    }
+
+    /// Get the character or format under the cursor, as markup.
+    pub fn content_get(&self) -> String {
+        unsafe {
+            let content = evas_textblock_cursor_content_get(self._cur);
+            let s = from_c_to_string(content).unwrap_or(String::new());
+            if content.is_not_null() { free(transmute(content)) }
+            s
+        }
+    }
+
+    /// Get the markup text of the paragraph of the cursor.
+    pub fn paragraph_text_get(&self) -> String {
+        let text = unsafe { evas_textblock_cursor_paragraph_text_get(self._cur) };
+        unsafe { from_c_to_string(text).unwrap_or(String::new()) }
+    }
+
+    /// Get the geometry of the cursor, as drawn by text editors,
+    /// or None if it is not in the formatted text.
+    pub fn geometry_get(&self, ctype: TextblockCursorType) -> Option<Geometry> {
+        let (mut x, mut y, mut w, mut h): (c_int, c_int, c_int, c_int) = (0, 0, 0, 0);
+        let mut dir: c_uint = 0;
+        let line = unsafe {
+            evas_textblock_cursor_geometry_get(self._cur, &mut x, &mut y, &mut w, &mut h,
+                                               &mut dir, ctype as c_uint)
+        };
+        if line < 0 { None } else { Some((x as int, y as int, w as int, h as int)) }
+    }
+
+    /// Get the geometry of the character under the cursor,
+    /// or None if it is not in the formatted text.
+    pub fn char_geometry_get(&self) -> Option<Geometry> {
+        let (mut x, mut y, mut w, mut h): (c_int, c_int, c_int, c_int) = (0, 0, 0, 0);
+        let line = unsafe {
+            evas_textblock_cursor_char_geometry_get(self._cur, &mut x, &mut y, &mut w, &mut h)
+        };
+        if line < 0 { None } else { Some((x as int, y as int, w as int, h as int)) }
+    }
+
+    /// Move the cursor to the character at the given position,
+    /// relative to the textblock.
+    pub fn char_coord_set(&self, xy: Coord) -> bool {
+        let (x, y) = xy;
+        unsafe {
+            from_eina_to_bool(evas_textblock_cursor_char_coord_set(self._cur,
+                                                                   x as c_int, y as c_int))
+        }
+    }
+
+    /// Compare the positions of two cursors of the same textblock.
+    pub fn compare(&self, other: &TextblockCursor) -> Ordering {
+        match unsafe { evas_textblock_cursor_compare(self._cur, other._cur) } {
+            n if n < 0 => Less,
+            0 => Equal,
+            _ => Greater
+        }
+    }
+
+    /// Move the cursor to the position of 'other'.
+    pub fn copy_from(&self, other: &TextblockCursor) {
+        unsafe { evas_textblock_cursor_copy(other._cur, self._cur) }
+    }
+}
+
+#[unsafe_destructor]
+impl<'r> Drop for TextblockCursor<'r> {
+    fn drop(&mut self) {
+        if self._owned { unsafe { evas_textblock_cursor_free(self._cur) } }
+    }
+}
+
+fn _color_get(obj: &EvasObject,
+              get: unsafe extern "C" fn (*EvasObject, *mut c_int, *mut c_int,
+                                         *mut c_int, *mut c_int)) -> Color {
+    let (mut r, mut g, mut b, mut a): (c_int, c_int, c_int, c_int) = (0, 0, 0, 0);
+    unsafe { get(obj, &mut r, &mut g, &mut b, &mut a) }
+    (r as int, g as int, b as int, a as int)
+}
@@ -0,0 +1,181 @@
+// Golden image snapshot testing for EFL Rust bindings.
+// Copyright (C) 2014  Luis Araujo <araujoc.luisf@gmail.com>
+
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 2.1 of the License, or (at your option) any later version.
+
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+
+/*
+ * Compare the rendering of a buffer canvas against reference PNG images.
+ *
+ * A mismatch writes the actual rendering and a diff image next to the
+ * reference, to be inspected or used as the new reference. A missing
+ * reference is an error: set RUST_EFL_UPDATE_SNAPSHOTS in the environment
+ * to record new references, or all of them again.
+ */
+
+use std::cmp;
+use std::os;
+
+use ecore;
+use evas;
+
+
+/// A premultiplied ARGB8888 image, stored row by row.
+#[deriving(Clone)]
+pub struct Image {
+    pub width: int,
+    pub height: int,
+    pub pixels: Vec<u32>
+}
+
+/// Reasons for a snapshot check to fail.
+#[deriving(Show)]
+pub enum SnapshotError {
+    /// The reference image does not exist, and recording is not enabled.
+    SnapshotMissing(String),
+    /// The reference image could not be loaded.
+    SnapshotLoadError(String),
+    /// An image could not be saved.
+    SnapshotSaveError(String),
+    /// The rendering and the reference have different sizes.
+    SnapshotSizeMismatch(evas::Coord, evas::Coord),
+    /// This number of pixels differ by more than the tolerance.
+    SnapshotMismatch(uint)
+}
+
+/* Environment variable forcing the references to be recorded again */
+static UPDATE_VAR: &'static str = "RUST_EFL_UPDATE_SNAPSHOTS";
+
+
+impl Image {
+    /// Render the canvas of a window created with
+    /// 'EcoreEvasWindow::buffer_new' and copy its pixels.
+    pub fn from_window(window: &ecore::EcoreEvasWindow) -> Image {
+        let (_, _, w, h) = window.geometry_get();
+        window.manual_render();
+        Image { width: w, height: h, pixels: window.buffer_pixels_get() }
+    }
+
+    /// Load the image from 'file', using the canvas 'e'.
+    pub fn load(e: &evas::Evas, file: &str) -> Result<Image, SnapshotError> {
+        let obj = evas::object_image_add(e);
+        evas::object_image_file_set(obj, file, None);
+        let (w, h) = evas::object_image_size_get(obj);
+        let stride = (evas::object_image_stride_get(obj) / 4) as uint;
+        let image = match (evas::object_image_load_error_get(obj),
+                           evas::object_image_data_get(obj)) {
+            (Ok(()), Some(data)) => {
+                let mut pixels: Vec<u32> = Vec::with_capacity((w * h) as uint);
+                for row in data.as_slice().chunks(stride) {
+                    pixels.push_all(row.slice_to(w as uint));
+                }
+                Ok(Image { width: w, height: h, pixels: pixels })
+            },
+            _ => Err(SnapshotLoadError(file.to_string()))
+        };
+        evas::object_del(obj);
+        image
+    }
+
+    /// Save the image to 'file', as PNG if its extension is ".png".
+    pub fn save(&self, e: &evas::Evas, file: &str) -> Result<(), SnapshotError> {
+        let obj = evas::object_image_add(e);
+        evas::object_image_size_set(obj, self.width, self.height);
+        evas::object_image_alpha_set(obj, true);
+        evas::object_image_data_copy_set(obj, self.pixels.as_slice());
+        let saved = evas::object_image_save_with_flags(obj, file, None, None, Some(9));
+        evas::object_del(obj);
+        match saved {
+            true => Ok(()),
+            false => Err(SnapshotSaveError(file.to_string()))
+        }
+    }
+
+    /// Get the pixel at the given position.
+    pub fn pixel_get(&self, x: int, y: int) -> u32 {
+        self.pixels[(y * self.width + x) as uint]
+    }
+}
+
+/// Compare two images of the same size, allowing each colour channel
+/// to differ by 'tolerance'.
+/// Returns the number of different pixels, and an image where they
+/// are painted in red over a faded copy of 'expected'.
+pub fn compare(actual: &Image, expected: &Image, tolerance: u8) -> (uint, Image) {
+    let mut mismatches: uint = 0;
+    let pixels: Vec<u32> = actual.pixels.iter().zip(expected.pixels.iter()).map(|(&a, &b)| {
+        if _pixel_matches(a, b, tolerance) {
+            /* Fade the expected pixel to a light grey */
+            let grey = (_channel(b, 16) + _channel(b, 8) + _channel(b, 0)) / 3;
+            let grey = 0xc0 + grey / 4;
+            0xff000000 | (grey << 16) | (grey << 8) | grey
+        } else {
+            mismatches += 1;
+            0xffff0000
+        }
+    }).collect();
+    (mismatches, Image { width: expected.width, height: expected.height, pixels: pixels })
+}
+
+/// Check the rendering of 'window' against the reference image 'reference'.
+/// The reference is only recorded when RUST_EFL_UPDATE_SNAPSHOTS is set.
+/// On mismatch, the rendering is saved to "<reference>.actual.png" and the differences to
+/// "<reference>.diff.png".
+pub fn check(window: &ecore::EcoreEvasWindow, reference: &str,
+             tolerance: u8) -> Result<(), SnapshotError> {
+    let e = window.evas_get();
+    let actual = Image::from_window(window);
+
+    if os::getenv(UPDATE_VAR).is_some() {
+        return actual.save(e, reference)
+    }
+    if !Path::new(reference).exists() {
+        return Err(SnapshotMissing(reference.to_string()))
+    }
+
+    let expected = try!(Image::load(e, reference));
+    if (actual.width, actual.height) != (expected.width, expected.height) {
+        try!(actual.save(e, format!("{}.actual.png", reference).as_slice()));
+        return Err(SnapshotSizeMismatch((actual.width, actual.height),
+                                        (expected.width, expected.height)))
+    }
+
+    match compare(&actual, &expected, tolerance) {
+        (0, _) => Ok(()),
+        (mismatches, diff) => {
+            try!(actual.save(e, format!("{}.actual.png", reference).as_slice()));
+            try!(diff.save(e, format!("{}.diff.png", reference).as_slice()));
+            Err(SnapshotMismatch(mismatches))
+        }
+    }
+}
+
+/// Like 'check', failing the current task on mismatch.
+pub fn assert_snapshot(window: &ecore::EcoreEvasWindow, reference: &str, tolerance: u8) {
+    match check(window, reference, tolerance) {
+        Ok(()) => (),
+        Err(err) => fail!("Snapshot {} does not match: {}", reference, err)
+    }
+}
+
+fn _channel(pixel: u32, shift: uint) -> u32 {
+    (pixel >> shift) & 0xff
+}
+
+fn _pixel_matches(a: u32, b: u32, tolerance: u8) -> bool {
+    range(0u, 4).all(|i| {
+        let (ca, cb) = (_channel(a, i * 8), _channel(b, i * 8));
+        cmp::max(ca, cb) - cmp::min(ca, cb) <= tolerance as u32
+    })
+}
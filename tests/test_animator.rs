@@ -0,0 +1,70 @@
+/*
+ * Test ecore animators, idlers and idle enterers/exiters.
+ *
+ * Slide a rectangle across the window with an eased timeline
+ * animation, then quit once it reaches the other side.
+ *
+ */
+
+extern crate efl;
+
+use efl::ecore;
+use efl::evas;
+
+static WIDTH: int = 320;
+static HEIGHT: int = 120;
+static SIZE: int = 40;
+
+
+fn main() {
+    ecore::evas_init();
+    let ee: Box<ecore::EcoreEvas> =
+        ecore::evas_new(None, 10, 10, WIDTH, HEIGHT, "");
+    ecore::evas_show(ee);
+
+    let e: Box<evas::Evas> = ecore::evas_get(ee);
+
+    let bg: Box<evas::EvasObject> = evas::object_rectangle_add(e);
+    evas::object_color_set(bg, 255, 255, 255, 255);
+    evas::object_resize(bg, WIDTH, HEIGHT);
+    evas::object_show(bg);
+
+    let rect: Box<evas::EvasObject> = evas::object_rectangle_add(e);
+    evas::object_color_set(rect, 0, 0, 255, 255);
+    evas::object_resize(rect, SIZE, SIZE);
+    evas::object_move(rect, (0, (HEIGHT - SIZE) / 2));
+    evas::object_show(rect);
+
+    /* Count how many times the main loop went idle */
+    let mut idles: uint = 0;
+    let _enterer = ecore::idle_enterer_add(box move |&mut:| {
+        idles += 1;
+        true
+    });
+    let _exiter = ecore::idle_exiter_add(box |&mut:| true);
+
+    /* Print a single message once everything is set up */
+    let _idler = ecore::idler_add(box |&mut:| {
+        println!("Main loop is idle, animation started");
+        false
+    });
+
+    ecore::animator_frametime_set(1.0 / 60.0);
+    /* The closure must own its state, so keep a raw pointer to the object */
+    let r: *evas::EvasObject = &*rect;
+    let _anim = ecore::animator_timeline_add(2.0, box move |&mut: pos: f64| {
+        let p = ecore::animator_pos_map(pos, ecore::EcorePosMapBounce, 1.8, 3.0);
+        let x = (p * (WIDTH - SIZE) as f64) as int;
+        evas::object_move(unsafe { &*r }, (x, (HEIGHT - SIZE) / 2));
+        if pos >= 1.0 {
+            println!("Animation done");
+            ecore::main_loop_quit();
+        }
+        true
+    });
+
+    ecore::main_loop_begin();
+
+    ecore::evas_free(ee);
+    ecore::evas_shutdown();
+}
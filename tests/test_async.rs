@@ -0,0 +1,68 @@
+/*
+ * Test futures driven by the ecore main loop.
+ *
+ * The same work as test_ls.rs and test_dbus.rs, written as a
+ * sequential chain instead of nested callbacks:
+ * wait a second, list the rust sources in the current dir,
+ * then introspect the DBus daemon and quit.
+ *
+ */
+
+extern crate efl;
+
+use efl::ecore;
+use efl::ecore::future;
+use efl::eio;
+use efl::eldbus;
+
+
+fn main() {
+    ecore::init();
+    eio::init();
+    eldbus::init();
+
+    let conn: Box<eldbus::EldbusConnection> =
+        eldbus::connection_get(eldbus::EldbusConnectionTypeSystem);
+    let obj: Box<eldbus::EldbusObject> =
+        eldbus::object_get(conn, "org.freedesktop.DBus", "/");
+    let proxy: Box<eldbus::EldbusProxy> =
+        eldbus::proxy_get(obj, "org.freedesktop.DBus.Introspectable");
+    let p: *eldbus::EldbusProxy = &*proxy;
+
+    let executor = ecore::future::Executor::new();
+
+    let task = future::and_then(future::timer(1.0), proc(_) {
+        println!("Listing files...");
+        eio::file_ls_future(".")
+    });
+    let task = future::and_then(task, proc(files) {
+        match files {
+            Err(error) => println!("Error listing files: {}", error),
+            Ok(files) => {
+                for f in files.iter().filter(|f| f.as_slice().ends_with(".rs")) {
+                    println!("Rust source: {}", f);
+                }
+            }
+        }
+        eldbus::proxy_call_future(unsafe { &*p }, "Introspect", -1.0)
+    });
+    let task = future::map(task, proc(reply) {
+        match reply {
+            Err((name, text)) => println!("Error: {}, {}", name, text),
+            Ok(_msg) => println!("Got introspection reply")
+        }
+        ecore::main_loop_quit();
+    });
+
+    executor.spawn(task);
+
+    ecore::main_loop_begin();
+
+    eldbus::proxy_unref(proxy);
+    eldbus::object_unref(obj);
+    eldbus::connection_unref(conn);
+
+    eldbus::shutdown();
+    eio::shutdown();
+    ecore::shutdown();
+}
@@ -0,0 +1,56 @@
+/*
+ * Test the headless buffer engine.
+ *
+ * Draw two rectangles on a canvas rendered into memory,
+ * without any display, then check the rendered pixels.
+ *
+ */
+
+extern crate efl;
+
+use efl::ecore;
+use efl::evas;
+
+static WIDTH: int = 64;
+static HEIGHT: int = 32;
+
+
+fn pixel_at(pixels: &Vec<u32>, x: int, y: int) -> u32 {
+    pixels[(y * WIDTH + x) as uint]
+}
+
+fn run() {
+    let canvas = ecore::EcoreEvasWindow::buffer_new(WIDTH, HEIGHT);
+    if !canvas.is_valid() {
+        println!("Could not create the buffer canvas");
+        fail!()
+    }
+    canvas.manual_render_set(true);
+
+    let e = canvas.evas_get();
+    let bg = evas::object_rectangle_add(e);
+    evas::object_color_set(bg, 255, 255, 255, 255);
+    evas::object_resize(bg, WIDTH, HEIGHT);
+    evas::object_show(bg);
+
+    let rect = evas::object_rectangle_add(e);
+    evas::object_color_set(rect, 255, 0, 0, 255);
+    evas::object_move(rect, (WIDTH / 2, 0));
+    evas::object_resize(rect, WIDTH / 2, HEIGHT);
+    evas::object_show(rect);
+
+    canvas.manual_render();
+    let pixels = canvas.buffer_pixels_get();
+    assert_eq!(pixels.len(), (WIDTH * HEIGHT) as uint);
+
+    println!("Left: {:08x}, right: {:08x}",
+             pixel_at(&pixels, 0, 0), pixel_at(&pixels, WIDTH - 1, 0));
+    assert_eq!(pixel_at(&pixels, 0, 0), 0xffffffff);
+    assert_eq!(pixel_at(&pixels, WIDTH - 1, HEIGHT - 1), 0xffff0000);
+}
+
+fn main() {
+    ecore::evas_init();
+    run();
+    ecore::evas_shutdown();
+}
@@ -0,0 +1,76 @@
+/*
+ * Test Ecore_Con server and client bindings.
+ *
+ * Start a line echo server on a local UNIX socket, connect to it,
+ * send a line and quit once it is echoed back.
+ * Pass 'tcp' as argument to use TCP on localhost instead.
+ *
+ */
+
+extern crate efl;
+
+use std::os;
+
+use efl::ecore;
+use efl::ecore::con;
+
+static PORT: int = 8080;
+
+
+fn main() {
+    let args: Vec<String> = os::args();
+    let (ctype, name) =
+        if args.len() > 1 && args.get(1).as_slice() == "tcp" {
+            (con::ECORE_CON_REMOTE_TCP, "127.0.0.1")
+        } else {
+            (con::ECORE_CON_LOCAL_USER, "rust-efl-echo")
+        };
+
+    ecore::init();
+    con::init();
+
+    /* Server side: echo back every chunk of data */
+    let _cl_add = con::event_client_add_handler_add(box |&mut: cl: &con::Client| {
+        println!("Server: client connected from {}", cl.ip_get());
+        true
+    });
+    let _cl_data = con::event_client_data_handler_add(
+        box |&mut: cl: &con::Client, data: &[u8]| {
+        print!("Server received: {}", String::from_utf8_lossy(data));
+        cl.send(data);
+        true
+    });
+
+    /* Client side: send a line once connected */
+    let _svr_add = con::event_server_add_handler_add(box |&mut: svr: &con::Server| {
+        println!("Client: connected to {}", svr.name_get());
+        svr.send("Hello Rust Enlightenment!\n".as_bytes());
+        true
+    });
+    let _svr_data = con::event_server_data_handler_add(
+        box |&mut: _svr: &con::Server, data: &[u8]| {
+        print!("Client received: {}", String::from_utf8_lossy(data));
+        ecore::main_loop_quit();
+        true
+    });
+    let _svr_error = con::event_server_error_handler_add(
+        box |&mut: _svr: &con::Server, error: &str| {
+        println!("Client error: {}", error);
+        ecore::main_loop_quit();
+        true
+    });
+
+    let server = con::server_add(ctype, name, PORT);
+    if !server.is_valid() {
+        println!("Could not start the server");
+        fail!()
+    }
+    let client = con::server_connect(ctype, name, PORT);
+
+    ecore::main_loop_begin();
+
+    client.del();
+    server.del();
+    con::shutdown();
+    ecore::shutdown();
+}
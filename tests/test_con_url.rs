@@ -0,0 +1,73 @@
+/*
+ * Test Ecore_Con_Url HTTP client bindings.
+ *
+ * Fetch an URL (by default from a local HTTP server, e.g. started with
+ * 'python -m SimpleHTTPServer 8000') and print the progress and status.
+ * If a file name is given, the response is written to it.
+ *
+ */
+
+extern crate efl;
+
+use std::os;
+use std::io::File;
+use std::os::unix::AsRawFd;
+
+use efl::ecore;
+use efl::ecore::con;
+
+
+fn main() {
+    let args: Vec<String> = os::args();
+    let url: String =
+        if args.len() > 1 { args.get(1).clone() }
+        else { "http://127.0.0.1:8000/".to_string() };
+
+    ecore::init();
+    con::url_init();
+
+    let transfer = con::url_new(url.as_slice());
+    if !transfer.is_valid() {
+        println!("Invalid URL: {}", url);
+        fail!()
+    }
+    transfer.additional_header_add("User-Agent", "rust-efl");
+    transfer.timeout_set(30.0);
+
+    /* Download to a file if requested */
+    let _file = if args.len() > 2 {
+        let f = File::create(&Path::new(args.get(2).as_slice())).unwrap();
+        transfer.fd_set(f.as_raw_fd());
+        Some(f)
+    } else { None };
+
+    let _data = con::event_url_data_handler_add(box |&mut: ev: &con::UrlEventData| {
+        println!("Received {} bytes", ev.data.len());
+        true
+    });
+    let _progress = con::event_url_progress_handler_add(
+        box |&mut: ev: &con::UrlEventProgress| {
+        if ev.down_total > 0.0 {
+            println!("Download: {}%", (ev.down_now * 100.0 / ev.down_total) as int);
+        }
+        true
+    });
+    let _complete = con::event_url_complete_handler_add(
+        box |&mut: ev: &con::UrlEventComplete| {
+        println!("Transfer complete with status {}", ev.status);
+        ecore::main_loop_quit();
+        true
+    });
+
+    if !transfer.get() {
+        println!("Could not start the transfer");
+        fail!()
+    }
+
+    ecore::main_loop_begin();
+
+    println!("Total received: {} bytes", transfer.received_bytes_get());
+    drop(transfer);
+    con::url_shutdown();
+    ecore::shutdown();
+}
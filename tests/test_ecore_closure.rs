@@ -0,0 +1,51 @@
+/*
+ * Test ecore timers and event handlers using closures.
+ *
+ * The closures own their state, so no statics or borrowed data
+ * are needed to keep track of the ticks.
+ */
+
+extern crate efl;
+
+use std::os;
+
+use efl::ecore;
+use efl::eseful::EventInfo;
+
+fn main() {
+    let args: Vec<String> = os::args();
+    let argc: uint = args.len();
+
+    ecore::init();
+    ecore::app_args_set(argc, args);
+
+    let start_time: f64 = ecore::time_get();
+    let mut ticks: uint = 0;
+
+    /* Tick five times, then let the timer go */
+    let timer = ecore::timer_add_closure(1.0, box move |&mut:| {
+        ticks += 1;
+        println!("Tick {}: {}", ticks, ecore::time_get() - start_time);
+        if ticks == 5 {
+            println!("Done ticking, quit.");
+            ecore::main_loop_quit();
+            return false
+        }
+        true
+    });
+
+    let handler = ecore::event_handler_add_closure(ecore::EcoreEventSignalExit,
+                                                   box |&mut: _htype: int, _event: &EventInfo| {
+        println!("Exit signal received after {} seconds",
+                 ecore::time_get() - start_time);
+        ecore::main_loop_quit();
+        true
+    });
+
+    /* Start main event loop */
+    ecore::main_loop_begin();
+
+    timer.del();
+    handler.del();
+    ecore::shutdown();
+}
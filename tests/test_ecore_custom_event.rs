@@ -0,0 +1,55 @@
+/*
+ * Test typed custom ecore events.
+ *
+ * Post a few typed events through the main loop, and quit on
+ * the exit signal (Ctrl-C) reported as a typed event too.
+ *
+ */
+
+extern crate efl;
+
+use efl::ecore;
+
+struct Progress {
+    step: uint,
+    message: String
+}
+
+
+fn main() {
+    ecore::init();
+
+    let progress: ecore::EventType<Progress> = ecore::EventType::new();
+    println!("New event type: {}", progress.id());
+
+    let handler = progress.handler_add(box |&mut: p: &Progress| {
+        println!("Step {}: {}", p.step, p.message);
+        if p.step == 3 {
+            println!("All steps received, press Ctrl-C to quit");
+        }
+        true
+    });
+
+    let exit = ecore::event_signal_exit_handler_add(
+        box |&mut: e: &ecore::EcoreEventSignalExit| {
+        if e.interrupt == 1 {
+            println!("Exit: interrupt");
+        } else if e.quit == 1 {
+            println!("Exit: quit");
+        } else if e.terminate == 1 {
+            println!("Exit: terminate");
+        }
+        ecore::main_loop_quit();
+        true
+    });
+
+    for step in range(1u, 4) {
+        progress.add(Progress { step: step, message: format!("working ({})", step) });
+    }
+
+    ecore::main_loop_begin();
+
+    handler.del();
+    exit.del();
+    ecore::shutdown();
+}
@@ -0,0 +1,93 @@
+/*
+ * Test input event injection.
+ *
+ * Click a button and type into an entry with an EventScript,
+ * checking that their "clicked" and "changed" callbacks fire.
+ * Run with ELM_ENGINE=buffer to test without a display.
+ *
+ */
+
+extern crate efl;
+
+use std::os;
+
+use efl::ecore;
+use efl::evas;
+use efl::elementary;
+use efl::eseful::EventInfo;
+
+struct Counts {
+    clicked: uint,
+    changed: uint
+}
+
+static mut COUNTS: Counts = Counts { clicked: 0, changed: 0 };
+
+
+fn on_clicked(_data: &Option<()>, _obj: &evas::EvasObject, _info: &EventInfo) {
+    unsafe { COUNTS.clicked += 1 }
+}
+
+fn on_changed(_data: &Option<()>, _obj: &evas::EvasObject, _info: &EventInfo) {
+    unsafe { COUNTS.changed += 1 }
+}
+
+fn main() {
+    let args: Vec<String> = os::args();
+    let argc: uint = args.len();
+    elementary::init(argc, args);
+
+    let win = elementary::win_util_standard_add("event-script", "Event script");
+    let ebox = elementary::box_add(win);
+    evas::object_size_hint_weight_set(ebox, evas::EVAS_HINT_EXPAND, evas::EVAS_HINT_EXPAND);
+    elementary::win_resize_object_add(win, ebox);
+    evas::object_show(ebox);
+
+    let button = elementary::button_add(win);
+    elementary::object_text_set(button, "Click me");
+    elementary::box_pack_end(ebox, button);
+    evas::object_show(button);
+
+    let entry = elementary::entry_add(win);
+    elementary::entry_single_line_set(entry, true);
+    evas::object_size_hint_weight_set(entry, evas::EVAS_HINT_EXPAND, 0.0);
+    evas::object_size_hint_align_set(entry, evas::EVAS_HINT_FILL, 0.5);
+    elementary::box_pack_end(ebox, entry);
+    evas::object_show(entry);
+
+    let none: Option<()> = None;
+    evas::object_smart_callback_add(button, "clicked", on_clicked, &none);
+    evas::object_smart_callback_add(entry, "changed,user", on_changed, &none);
+
+    evas::object_resize(win, 240, 120);
+    evas::object_show(win);
+
+    /* Drive the widgets once they are laid out */
+    let (w, b, en): (*evas::EvasObject, *evas::EvasObject, *evas::EvasObject) =
+        (&*win, &*button, &*entry);
+    let _script_timer = ecore::timer_add_closure(0.5, box move |&mut:| {
+        let (w, b, en) = unsafe { (&*w, &*b, &*en) };
+        evas::EventScript::new()
+            .click_object(b)
+            .click_object(b)
+            .click_object(en)
+            .type_text("hello")
+            .key("BackSpace")
+            .run(evas::object_evas_get(w));
+        false
+    });
+
+    let _check_timer = ecore::timer_add_closure(1.5, box move |&mut:| {
+        let en = unsafe { &*en };
+        let counts = unsafe { &COUNTS };
+        println!("Clicked: {}, changed: {}, text: {}",
+                 counts.clicked, counts.changed, elementary::entry_entry_get(en));
+        assert_eq!(counts.clicked, 2);
+        assert!(counts.changed > 0);
+        elementary::exit();
+        false
+    });
+
+    elementary::run();
+    elementary::shutdown();
+}
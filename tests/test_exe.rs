@@ -0,0 +1,58 @@
+/*
+ * Test Ecore_Exe child process bindings.
+ *
+ * Spawn 'cat', send it a few lines through its stdin and print
+ * back what it echoes until it exits.
+ *
+ */
+
+extern crate efl;
+
+use efl::ecore;
+use efl::ecore::exe;
+
+
+fn main() {
+    ecore::init();
+
+    let _add = exe::event_add_handler_add(box |&mut: ev: &exe::ExeEventAdd| {
+        println!("Child started: {}", ev.pid);
+        true
+    });
+
+    let _data = exe::event_data_handler_add(box |&mut: ev: &exe::ExeEventData| {
+        for line in ev.lines.iter() {
+            println!("Child says: {}", line);
+        }
+        true
+    });
+
+    let _error = exe::event_error_handler_add(box |&mut: ev: &exe::ExeEventData| {
+        println!("Child error: {} bytes", ev.data.len());
+        true
+    });
+
+    let _del = exe::event_del_handler_add(box |&mut: ev: &exe::ExeEventDel| {
+        if ev.exited {
+            println!("Child {} exited with code {}", ev.pid, ev.exit_code);
+        } else if ev.signalled {
+            println!("Child {} killed by signal {}", ev.pid, ev.exit_signal);
+        }
+        ecore::main_loop_quit();
+        true
+    });
+
+    let child = exe::pipe_run("cat",
+                              exe::ECORE_EXE_PIPE_READ |
+                              exe::ECORE_EXE_PIPE_WRITE |
+                              exe::ECORE_EXE_PIPE_ERROR |
+                              exe::ECORE_EXE_PIPE_READ_LINE_BUFFERED);
+    println!("Running: {}", child.cmd_get().unwrap());
+
+    child.send("Hello\n".as_bytes());
+    child.send("Rust Enlightenment!\n".as_bytes());
+    child.close_stdin();
+
+    ecore::main_loop_begin();
+    ecore::shutdown();
+}
@@ -0,0 +1,56 @@
+/*
+ * Test ecore file descriptor handlers.
+ *
+ * Watch the standard input from the main loop and echo back
+ * everything typed, until EOF (Ctrl-D).
+ *
+ */
+
+extern crate libc;
+extern crate efl;
+
+use libc::{c_void, size_t};
+use std::mem::transmute;
+
+use efl::ecore;
+
+
+fn main() {
+    ecore::init();
+
+    let mut total: uint = 0;
+    let stdin_fd: ecore::RawFd = 0;
+
+    let handler = ecore::main_fd_handler_add(stdin_fd,
+                                             ecore::ECORE_FD_READ | ecore::ECORE_FD_ERROR,
+                                             box move |&mut: fd: ecore::RawFd,
+                                                       active: ecore::FdHandlerFlags| {
+        if active & ecore::ECORE_FD_ERROR != 0 {
+            println!("Error on fd {}", fd);
+            ecore::main_loop_quit();
+            return false
+        }
+
+        let mut buf = [0u8, ..1024];
+        let n = unsafe {
+            libc::read(fd, transmute::<*mut u8, *mut c_void>(buf.as_mut_ptr()),
+                       buf.len() as size_t)
+        };
+        if n <= 0 {
+            println!("EOF, read {} bytes in total", total);
+            ecore::main_loop_quit();
+            return false
+        }
+
+        total += n as uint;
+        print!("Read: {}", String::from_utf8_lossy(buf.slice_to(n as uint)));
+        true
+    });
+
+    println!("Watching fd {}, type something:", handler.fd_get());
+
+    ecore::main_loop_begin();
+
+    handler.del();
+    ecore::shutdown();
+}
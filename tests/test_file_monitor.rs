@@ -0,0 +1,61 @@
+/*
+ * Test Ecore_File utilities and file monitors.
+ *
+ * Create a scratch directory, watch it for changes, then create,
+ * modify and remove files in it, printing every reported change.
+ *
+ */
+
+extern crate efl;
+
+use std::io::File;
+
+use efl::ecore;
+use efl::ecore::file;
+
+static DIR: &'static str = "/tmp/rust-efl-monitor/sub/dir";
+
+
+fn main() {
+    ecore::init();
+    file::init();
+
+    println!("sh installed: {}", file::app_installed("sh"));
+
+    if !file::mkpath(DIR) {
+        println!("Could not create {}", DIR);
+        fail!()
+    }
+    println!("Created {} (is dir: {})", file::realpath(DIR), file::is_dir(DIR));
+    println!("Parent: {}, name: {}", file::dir_get(DIR), file::file_get(DIR));
+
+    let monitor = file::monitor_add(DIR, box |&mut: ev: file::FileMonitorEvent, path: &str| {
+        println!("{}: {}", ev, path);
+        if ev == file::EcoreFileEventDeletedSelf {
+            ecore::main_loop_quit();
+        }
+    });
+    println!("Monitoring: {}", monitor.path_get());
+
+    /* Make some changes once the main loop runs */
+    let mut step: uint = 0;
+    let _timer = ecore::timer_add_closure(0.5, box move |&mut:| {
+        let a = format!("{}/a.txt", DIR);
+        let b = format!("{}/b.txt", DIR);
+        step += 1;
+        match step {
+            1 => { File::create(&Path::new(a.as_slice())).write_str("hello").unwrap(); }
+            2 => { file::cp(a.as_slice(), b.as_slice()); }
+            3 => { println!("Files: {}", file::ls(DIR)); }
+            4 => { file::remove(a.as_slice()); }
+            _ => { file::recursive_rm("/tmp/rust-efl-monitor"); return false }
+        }
+        true
+    });
+
+    ecore::main_loop_begin();
+
+    monitor.del();
+    file::shutdown();
+    ecore::shutdown();
+}
@@ -0,0 +1,62 @@
+/*
+ * Test reading back geometry, colour, names and size hints of
+ * Evas objects on a buffer canvas.
+ *
+ */
+
+extern crate efl;
+
+use efl::ecore;
+use efl::evas;
+use efl::evas::{EvasAspectControlBoth, EvasDisplayModeCompress};
+
+
+fn run() {
+    let canvas = ecore::EcoreEvasWindow::buffer_new(320, 240);
+    let e = canvas.evas_get();
+
+    let rect = evas::object_rectangle_add(e);
+    evas::object_name_set(rect, "box");
+    evas::object_geometry_set(rect, (10, 20, 100, 50));
+    evas::object_color_set(rect, 128, 64, 32, 255);
+    assert!(!evas::object_visible_get(rect));
+    evas::object_show(rect);
+    assert!(evas::object_visible_get(rect));
+
+    assert_eq!(evas::object_geometry_get(rect), (10, 20, 100, 50));
+    evas::object_move(rect, (30, 40));
+    evas::object_resize(rect, 60, 30);
+    assert_eq!(evas::object_geometry_get(rect), (30, 40, 60, 30));
+    assert_eq!(evas::object_color_get(rect), (128, 64, 32, 255));
+
+    assert_eq!(evas::object_name_get(rect), Some("box".to_string()));
+    assert!(evas::object_name_find(e, "box").is_some());
+    assert!(evas::object_name_find(e, "nothing").is_none());
+    assert_eq!(evas::object_type_get(rect), Some("rectangle".to_string()));
+    assert_eq!(evas::object_evas_get(rect) as *evas::Evas, e as *evas::Evas);
+
+    /* Size hints */
+    evas::object_size_hint_min_set(rect, 20, 10);
+    evas::object_size_hint_max_set(rect, 200, -1);
+    evas::object_size_hint_request_set(rect, 80, 40);
+    evas::object_size_hint_aspect_set(rect, EvasAspectControlBoth, 2, 1);
+    evas::object_size_hint_padding_set(rect, (1, 2, 3, 4));
+    evas::object_size_hint_display_mode_set(rect, EvasDisplayModeCompress);
+    evas::object_size_hint_weight_set(rect, evas::EVAS_HINT_EXPAND, 0.0);
+    evas::object_size_hint_align_set(rect, evas::EVAS_HINT_FILL, 0.5);
+
+    assert_eq!(evas::object_size_hint_min_get(rect), (20, 10));
+    assert_eq!(evas::object_size_hint_max_get(rect), (200, -1));
+    assert_eq!(evas::object_size_hint_request_get(rect), (80, 40));
+    assert_eq!(evas::object_size_hint_aspect_get(rect), (EvasAspectControlBoth, 2, 1));
+    assert_eq!(evas::object_size_hint_padding_get(rect), (1, 2, 3, 4));
+    assert_eq!(evas::object_size_hint_display_mode_get(rect), EvasDisplayModeCompress);
+    assert_eq!(evas::object_size_hint_weight_get(rect), (evas::EVAS_HINT_EXPAND, 0.0));
+    assert_eq!(evas::object_size_hint_align_get(rect), (evas::EVAS_HINT_FILL, 0.5));
+}
+
+fn main() {
+    ecore::evas_init();
+    run();
+    ecore::evas_shutdown();
+}
@@ -0,0 +1,70 @@
+/*
+ * Test Ecore_Getopt command line parsing.
+ *
+ * Try for example:
+ *   test_getopt -v -v --name=foo -I /usr -I /opt --size 3 --mode fast a b
+ *   test_getopt --help
+ *
+ */
+
+extern crate efl;
+
+use std::os;
+
+use efl::ecore;
+use efl::ecore::getopt;
+
+
+fn main() {
+    ecore::init();
+
+    let parser = getopt::Parser::new("test_getopt")
+        .usage("%prog [options] <files>")
+        .version("0.1")
+        .copyright("(C) 2014 Luis Araujo")
+        .license("LGPL v2.1")
+        .description("Show how command line options are parsed.")
+        .count('v', "verbose", "be more verbose.")
+        .store_true('q', "quiet", "print nothing.")
+        .store('n', "name", "name to greet.", "NAME", getopt::GetoptTypeStr)
+        .store('s', "size", "a size.", "SIZE", getopt::GetoptTypeInt)
+        .append('I', "include", "add a directory.", "DIR")
+        .choice('m', "mode", "run mode.", "MODE", &["fast", "slow"])
+        .version_action('V', "version")
+        .license_action('L', "license")
+        .help('h', "help");
+
+    /* Options left out of the command line have no value */
+    let defaults = parser.parse(vec!["test_getopt".to_string()]).unwrap();
+    for name in ["verbose", "quiet", "name", "size", "mode"].iter() {
+        assert!(defaults.get(*name).is_none());
+    }
+    assert!(defaults.list_get("include").is_empty());
+
+    let values = match parser.parse(os::args()) {
+        None => {
+            println!("Could not parse the command line");
+            parser.print_help();
+            fail!()
+        },
+        Some(values) => values
+    };
+
+    /* --help, --version or --license were given */
+    if values.quit {
+        ecore::shutdown();
+        return
+    }
+
+    if !values.bool_get("quiet") {
+        println!("Verbosity: {}", values.count_get("verbose"));
+        println!("Name: {}", values.str_get("name").unwrap_or("world".to_string()));
+        println!("Size: {}", values.int_get("size"));
+        println!("Include: {}", values.list_get("include"));
+        println!("Mode: {}", values.str_get("mode"));
+        println!("Files: {}", values.args);
+        println!("App args: {}", ecore::app_args_get());
+    }
+
+    ecore::shutdown();
+}
@@ -0,0 +1,85 @@
+/*
+ * Test Evas image pixel data.
+ *
+ * Draw a gradient procedurally into an image object, save it as PNG
+ * and JPEG, then load the PNG back and compare some pixels.
+ *
+ */
+
+extern crate efl;
+
+use std::os;
+
+use efl::ecore;
+use efl::evas;
+use efl::evas::{EvasColorspaceArgb8888, EvasLoadErrorDoesNotExist};
+
+static SIZE: int = 64;
+
+
+fn run() {
+    let canvas = ecore::EcoreEvasWindow::buffer_new(SIZE, SIZE);
+    let e = canvas.evas_get();
+
+    let image = evas::object_image_filled_add(e);
+    evas::object_image_colorspace_set(image, EvasColorspaceArgb8888);
+    assert_eq!(evas::object_image_colorspace_get(image), Some(EvasColorspaceArgb8888));
+    evas::object_image_size_set(image, SIZE, SIZE);
+    evas::object_image_alpha_set(image, false);
+    assert!(!evas::object_image_alpha_get(image));
+    evas::object_image_smooth_scale_set(image, false);
+    assert!(!evas::object_image_smooth_scale_get(image));
+    evas::object_image_border_set(image, (2, 2, 2, 2));
+    assert_eq!(evas::object_image_border_get(image), (2, 2, 2, 2));
+    evas::object_resize(image, SIZE, SIZE);
+    evas::object_show(image);
+
+    let stride = (evas::object_image_stride_get(image) / 4) as uint;
+    {
+        let mut data = evas::object_image_data_get_mut(image).unwrap();
+        assert_eq!(data.as_slice().len(), stride * SIZE as uint);
+        /* The pixels are exclusively borrowed */
+        assert!(evas::object_image_data_get(image).is_none());
+        assert!(evas::object_image_data_get_mut(image).is_none());
+        for (y, row) in data.as_mut_slice().chunks_mut(stride).enumerate() {
+            for (x, pixel) in row.iter_mut().enumerate() {
+                *pixel = 0xff000000 | (x as u32 * 4) << 16 | (y as u32 * 4) << 8;
+            }
+        }
+    }
+    /* Given back to the image once the guard is dropped */
+    assert!(evas::object_image_data_get(image).is_some());
+    evas::object_image_data_update_add(image, (0, 0, SIZE, SIZE));
+    canvas.manual_render();
+
+    let dir = os::tmpdir();
+    let png = dir.join("rust_efl_gradient.png");
+    let jpg = dir.join("rust_efl_gradient.jpg");
+    let png = png.as_str().unwrap();
+    assert!(evas::object_image_extension_can_load_get(png));
+    assert!(evas::object_image_save_with_flags(image, png, None, None, Some(9)));
+    assert!(evas::object_image_save_with_flags(image, jpg.as_str().unwrap(), None,
+                                               Some(90), None));
+
+    let loaded = evas::object_image_add(e);
+    evas::object_image_file_set(loaded, png, None);
+    assert_eq!(evas::object_image_load_error_get(loaded), Ok(()));
+    assert_eq!(evas::object_image_size_get(loaded), (SIZE, SIZE));
+    {
+        let stride = (evas::object_image_stride_get(loaded) / 4) as uint;
+        let data = evas::object_image_data_get(loaded).unwrap();
+        let other = evas::object_image_data_get(loaded).unwrap();
+        assert!(evas::object_image_data_get_mut(loaded).is_none());
+        assert_eq!(data.as_slice()[0], 0xff000000);
+        assert_eq!(other.as_slice()[10 * stride + 20], 0xff000000 | 80 << 16 | 40 << 8);
+    }
+
+    evas::object_image_file_set(loaded, "/nonexistent/image.png", None);
+    assert_eq!(evas::object_image_load_error_get(loaded), Err(EvasLoadErrorDoesNotExist));
+}
+
+fn main() {
+    ecore::evas_init();
+    run();
+    ecore::evas_shutdown();
+}
@@ -0,0 +1,74 @@
+/*
+ * Test typed input event callbacks.
+ *
+ * Feed mouse and key events to a rectangle of a buffer canvas
+ * and print the typed information its callbacks get.
+ *
+ */
+
+extern crate efl;
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use efl::ecore;
+use efl::eina;
+use efl::evas;
+use efl::evas::event::{MouseDown, MouseUp, MouseWheel, KeyDown};
+
+
+fn run() {
+    let canvas = ecore::EcoreEvasWindow::buffer_new(100, 100);
+    let e = canvas.evas_get();
+    evas::focus_in(e);
+
+    let rect = evas::object_rectangle_add(e);
+    evas::object_move(rect, (10, 10));
+    evas::object_resize(rect, 80, 80);
+    evas::object_show(rect);
+    evas::object_focus_set(rect, eina::EINA_TRUE);
+
+    let count = Rc::new(Cell::new(0u));
+
+    let c = count.clone();
+    evas::event::object_event_closure_add(rect, box move |&mut: _obj: &evas::EvasObject,
+                                                         ev: &MouseDown| {
+        println!("Mouse down: button {} at {}", ev.button, ev.canvas);
+        assert_eq!(ev.canvas, (50, 50));
+        c.set(c.get() + 1);
+    });
+    let c = count.clone();
+    evas::event::object_event_closure_add(rect, box move |&mut: _obj: &evas::EvasObject,
+                                                         ev: &MouseUp| {
+        println!("Mouse up: button {}", ev.button);
+        c.set(c.get() + 1);
+    });
+    let c = count.clone();
+    evas::event::object_event_closure_add(rect, box move |&mut: _obj: &evas::EvasObject,
+                                                         ev: &MouseWheel| {
+        println!("Wheel: {} (direction {})", ev.z, ev.direction);
+        c.set(c.get() + 1);
+    });
+    let c = count.clone();
+    evas::event::object_event_closure_add(rect, box move |&mut: _obj: &evas::EvasObject,
+                                                         ev: &KeyDown| {
+        println!("Key down: {} {} (shift: {})",
+                 ev.keyname, ev.string, ev.modifiers.is_set("Shift"));
+        c.set(c.get() + 1);
+    });
+
+    evas::EventScript::new()
+        .click_object(rect)
+        .wheel(-1)
+        .type_text("ok")
+        .run(e);
+
+    /* Down, up, wheel and two keys */
+    assert_eq!(count.get(), 5);
+}
+
+fn main() {
+    ecore::evas_init();
+    run();
+    ecore::evas_shutdown();
+}
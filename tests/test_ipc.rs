@@ -0,0 +1,63 @@
+/*
+ * Test Ecore_Ipc bindings.
+ *
+ * Run a backend IPC server and a UI client in the same process
+ * over a local UNIX socket: the client sends a request, the backend
+ * answers it, and the client quits once the response arrives.
+ *
+ */
+
+extern crate efl;
+
+use efl::ecore;
+use efl::ecore::ipc;
+
+static OP_GREET: int = 1;
+
+
+fn main() {
+    ecore::init();
+    ipc::init();
+
+    /* Backend side: answer every greeting */
+    let _cl_data = ipc::event_client_data_handler_add(
+        box |&mut: cl: &ipc::Client, msg: &ipc::Message| {
+        println!("Backend got request {} (major {}, minor {}): {}",
+                 msg.reference, msg.major, msg.minor,
+                 String::from_utf8_lossy(msg.data.as_slice()));
+        if msg.major == OP_GREET {
+            cl.send(&msg.reply("Hello from the backend!".as_bytes()));
+        }
+        true
+    });
+
+    /* UI side: send the request once connected, wait for the answer */
+    let _svr_add = ipc::event_server_add_handler_add(box |&mut: svr: &ipc::Server| {
+        let mut msg = ipc::Message::new(OP_GREET, 0, "Hello from the UI!".as_bytes());
+        msg.reference = 42;
+        msg.response = 1;
+        svr.send(&msg);
+        true
+    });
+    let _svr_data = ipc::event_server_data_handler_add(
+        box |&mut: _svr: &ipc::Server, msg: &ipc::Message| {
+        println!("UI got response to {}: {}", msg.ref_to,
+                 String::from_utf8_lossy(msg.data.as_slice()));
+        ecore::main_loop_quit();
+        true
+    });
+
+    let backend = ipc::server_add(ipc::ECORE_IPC_LOCAL_USER, "rust-efl-ipc", 0);
+    if !backend.is_valid() {
+        println!("Could not start the backend");
+        fail!()
+    }
+    let ui = ipc::server_connect(ipc::ECORE_IPC_LOCAL_USER, "rust-efl-ipc", 0);
+
+    ecore::main_loop_begin();
+
+    ui.del();
+    backend.del();
+    ipc::shutdown();
+    ecore::shutdown();
+}
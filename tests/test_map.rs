@@ -0,0 +1,81 @@
+/*
+ * Test Evas maps.
+ *
+ * Spin a rectangle in 3D with perspective and lighting, while
+ * another one rotates and zooms in 2D. Quit once they are done.
+ *
+ */
+
+extern crate efl;
+
+use efl::ecore;
+use efl::evas;
+use efl::evas::map::{EvasMap, object_map_set, object_map_enable_set};
+
+static WIDTH: int = 400;
+static HEIGHT: int = 200;
+
+
+fn run() {
+    let window = ecore::EcoreEvasWindow::new(None, 10, 10, WIDTH, HEIGHT, "");
+    let e = window.evas_get();
+
+    let bg = evas::object_rectangle_add(e);
+    evas::object_color_set(bg, 255, 255, 255, 255);
+    evas::object_resize(bg, WIDTH, HEIGHT);
+    evas::object_show(bg);
+
+    let cube = evas::object_rectangle_add(e);
+    evas::object_color_set(cube, 200, 0, 0, 255);
+    evas::object_move(cube, (50, 50));
+    evas::object_resize(cube, 100, 100);
+    evas::object_show(cube);
+
+    let square = evas::object_rectangle_add(e);
+    evas::object_color_set(square, 0, 0, 200, 255);
+    evas::object_move(square, (250, 50));
+    evas::object_resize(square, 100, 100);
+    evas::object_show(square);
+
+    /* Evas only supports maps of 4 points */
+    assert!(EvasMap::new(3).is_none());
+    assert_eq!(EvasMap::new(4).unwrap().count_get(), 4);
+
+    /* Per point colours give a gradient */
+    let mut gradient = EvasMap::from_object(square);
+    gradient.point_color_set(0, 255, 255, 255, 255);
+    gradient.point_color_set(2, 0, 0, 0, 255);
+    println!("Points: {}, first: {}, uv: {}", gradient.count_get(),
+             gradient.point_coord_get(0), gradient.point_image_uv_get(2));
+
+    let (c, s): (*evas::EvasObject, *evas::EvasObject) = (&*cube, &*square);
+    let _anim = ecore::animator_timeline_add(3.0, box move |&mut: pos: f64| {
+        let (cube, square) = unsafe { (&*c, &*s) };
+
+        let mut map = EvasMap::from_object(cube);
+        map.util_3d_rotate(0.0, pos * 360.0, 0.0, (100, 100), 0);
+        map.util_3d_lighting((100, 100), -100, (255, 255, 255), (40, 40, 40));
+        map.util_3d_perspective((100, 100), 0, 256);
+        object_map_set(cube, Some(&map));
+        object_map_enable_set(cube, map.util_clockwise_get());
+
+        let mut map = gradient.clone();
+        map.util_rotate(pos * 90.0, (300, 100));
+        map.util_zoom(1.0 + pos, 1.0 + pos, (300, 100));
+        map.smooth_set(true);
+        object_map_set(square, Some(&map));
+        object_map_enable_set(square, true);
+
+        if pos >= 1.0 { ecore::main_loop_quit() }
+        true
+    });
+
+    window.show();
+    ecore::main_loop_begin();
+}
+
+fn main() {
+    ecore::evas_init();
+    run();
+    ecore::evas_shutdown();
+}
@@ -0,0 +1,72 @@
+/*
+ * Test Evas line and polygon objects.
+ *
+ * Draw a small chart: a filled area under a polyline, with axes,
+ * and check the rendering in a few points.
+ *
+ */
+
+extern crate efl;
+
+use efl::ecore;
+use efl::evas;
+
+static WIDTH: int = 200;
+static HEIGHT: int = 100;
+
+
+fn run() {
+    let canvas = ecore::EcoreEvasWindow::buffer_new(WIDTH, HEIGHT);
+    let e = canvas.evas_get();
+
+    let bg = evas::object_rectangle_add(e);
+    evas::object_color_set(bg, 255, 255, 255, 255);
+    evas::object_resize(bg, WIDTH, HEIGHT);
+    evas::object_show(bg);
+
+    let values = [(10, 80), (50, 40), (90, 60), (130, 20), (190, 30)];
+
+    /* Area under the curve */
+    let mut area: Vec<evas::Coord> = vec![(10, 90)];
+    area.push_all(values.as_slice());
+    area.push((190, 90));
+    let fill = evas::object_polygon_from_points(e, area.as_slice());
+    evas::object_color_set(fill, 0, 0, 128, 128);
+    evas::object_show(fill);
+
+    let lines = evas::object_polyline_add(e, values.as_slice());
+    assert_eq!(lines.len(), values.len() - 1);
+    for line in lines.iter() {
+        evas::object_color_set(&**line, 0, 0, 255, 255);
+        evas::object_show(&**line);
+    }
+    assert_eq!(evas::object_line_xy_get(&*lines[1]), ((50, 40), (90, 60)));
+
+    /* Axes */
+    let x_axis = evas::object_line_add(e);
+    evas::object_line_xy_set(x_axis, (10, 90), (190, 90));
+    let y_axis = evas::object_line_add(e);
+    evas::object_line_xy_set(y_axis, (10, 90), (10, 10));
+    for axis in [&x_axis, &y_axis].iter() {
+        evas::object_color_set(&***axis, 0, 0, 0, 255);
+        evas::object_show(&***axis);
+    }
+
+    canvas.manual_render();
+    let pixels = canvas.buffer_pixels_get();
+    let pixel = |x: int, y: int| pixels[(y * WIDTH + x) as uint];
+    println!("Inside area: {:08x}, outside: {:08x}", pixel(130, 80), pixel(130, 5));
+    assert!(pixel(130, 80) != pixel(130, 5));
+    assert_eq!(pixel(130, 5), 0xffffffff);
+
+    /* Empty the polygon, its area is no longer drawn */
+    evas::object_polygon_points_clear(fill);
+    canvas.manual_render();
+    assert_eq!(canvas.buffer_pixels_get()[(80 * WIDTH + 130) as uint], 0xffffffff);
+}
+
+fn main() {
+    ecore::evas_init();
+    run();
+    ecore::evas_shutdown();
+}
@@ -0,0 +1,115 @@
+/*
+ * Test smart objects written in Rust.
+ *
+ * A "swatch" smart object shows a coloured square with a border.
+ * It lays out its members when resized and emits "clicked" when
+ * pressed, which is caught with evas::object_smart_callback_add.
+ *
+ */
+
+extern crate efl;
+
+use efl::ecore;
+use efl::evas;
+use efl::evas::smart;
+use efl::evas::event::MouseDown;
+use efl::eseful::EventInfo;
+
+static mut CLICKS: uint = 0;
+
+struct Swatch {
+    color: (int, int, int),
+    geometry: (int, int, int, int),
+    border: Option<Box<evas::EvasObject>>,
+    fill: Option<Box<evas::EvasObject>>
+}
+
+impl smart::Smart for Swatch {
+    fn add(&mut self, obj: &evas::EvasObject) {
+        let e = evas::object_evas_get(obj);
+        let border = evas::object_rectangle_add(e);
+        evas::object_color_set(border, 0, 0, 0, 255);
+        smart::object_smart_member_add(border, obj);
+
+        let fill = evas::object_rectangle_add(e);
+        let (r, g, b) = self.color;
+        evas::object_color_set(fill, r, g, b, 255);
+        smart::object_smart_member_add(fill, obj);
+
+        /* Events of the members propagate to the smart object */
+        evas::event::object_event_closure_add(obj, box |&mut: o: &evas::EvasObject,
+                                                            _ev: &MouseDown| {
+            smart::object_smart_callback_call(o, "clicked")
+        });
+        evas::object_show(border);
+        evas::object_show(fill);
+        self.border = Some(border);
+        self.fill = Some(fill);
+    }
+
+    fn move_to(&mut self, obj: &evas::EvasObject, x: int, y: int) {
+        let (_, _, w, h) = self.geometry;
+        self.geometry = (x, y, w, h);
+        smart::object_smart_changed(obj);
+    }
+
+    fn resize(&mut self, obj: &evas::EvasObject, w: int, h: int) {
+        println!("Swatch resized to {}x{}", w, h);
+        let (x, y, _, _) = self.geometry;
+        self.geometry = (x, y, w, h);
+        smart::object_smart_changed(obj);
+    }
+
+    fn calculate(&mut self, _obj: &evas::EvasObject) {
+        let (x, y, w, h) = self.geometry;
+        match (&self.border, &self.fill) {
+            (&Some(ref border), &Some(ref fill)) => {
+                evas::object_move(&**border, (x, y));
+                evas::object_resize(&**border, w, h);
+                evas::object_move(&**fill, (x + 2, y + 2));
+                evas::object_resize(&**fill, w - 4, h - 4);
+            },
+            _ => ()
+        }
+    }
+
+    fn del(&mut self, _obj: &evas::EvasObject) {
+        println!("Swatch deleted");
+    }
+}
+
+fn on_clicked(_data: &Option<()>, _obj: &evas::EvasObject, _info: &EventInfo) {
+    unsafe { CLICKS += 1 }
+}
+
+fn run() {
+    let canvas = ecore::EcoreEvasWindow::buffer_new(200, 200);
+    let e = canvas.evas_get();
+
+    let class = smart::smart_class_new::<Swatch>("Swatch");
+    let swatch = class.object_add(e, Swatch {
+        color: (255, 128, 0), geometry: (0, 0, 0, 0), border: None, fill: None
+    });
+    evas::object_move(swatch, (50, 50));
+    evas::object_resize(swatch, 100, 100);
+    evas::object_show(swatch);
+
+    let none: Option<()> = None;
+    evas::object_smart_callback_add(swatch, "clicked", on_clicked, &none);
+
+    canvas.manual_render();
+    let pixels = canvas.buffer_pixels_get();
+    println!("Centre pixel: {:08x}", pixels[100 * 200 + 100]);
+
+    evas::EventScript::new().click((100, 100)).click((10, 10)).run(e);
+    assert_eq!(unsafe { CLICKS }, 1);
+
+    assert_eq!(class.data_get(swatch).unwrap().color, (255, 128, 0));
+    evas::object_del(swatch);
+}
+
+fn main() {
+    ecore::evas_init();
+    run();
+    ecore::evas_shutdown();
+}
@@ -0,0 +1,69 @@
+/*
+ * Test golden image snapshots.
+ *
+ * Render a simple scene on a buffer canvas and check it against
+ * the reference image committed in tests/snapshots.
+ * Run with RUST_EFL_UPDATE_SNAPSHOTS set to record it again.
+ *
+ */
+
+extern crate efl;
+
+use std::os;
+
+use efl::ecore;
+use efl::evas;
+use efl::snapshot;
+
+static WIDTH: int = 120;
+static HEIGHT: int = 80;
+static REFERENCE: &'static str = "tests/snapshots/simple_scene.png";
+
+
+fn run() {
+    let canvas = ecore::EcoreEvasWindow::buffer_new(WIDTH, HEIGHT);
+    canvas.manual_render_set(true);
+    let e = canvas.evas_get();
+
+    let bg = evas::object_rectangle_add(e);
+    evas::object_color_set(bg, 255, 255, 255, 255);
+    evas::object_resize(bg, WIDTH, HEIGHT);
+    evas::object_show(bg);
+
+    let rect = evas::object_rectangle_add(e);
+    evas::object_color_set(rect, 0, 128, 0, 255);
+    evas::object_move(rect, (20, 20));
+    evas::object_resize(rect, 40, 40);
+    evas::object_show(rect);
+
+    snapshot::assert_snapshot(&*canvas, REFERENCE, 2);
+
+    /* Moving the rectangle must be noticed */
+    evas::object_move(rect, (30, 20));
+    let reference = snapshot::Image::load(e, REFERENCE).unwrap();
+    let (mismatches, _) = snapshot::compare(&snapshot::Image::from_window(&*canvas),
+                                            &reference, 2);
+    println!("{} pixels differ, as expected", mismatches);
+    assert!(mismatches > 0);
+
+    /* Missing references are not recorded silently */
+    if os::getenv("RUST_EFL_UPDATE_SNAPSHOTS").is_none() {
+        match snapshot::check(&*canvas, "tests/snapshots/missing.png", 2) {
+            Err(snapshot::SnapshotMissing(_)) => (),
+            other => fail!("Unexpected result: {}", other)
+        }
+    }
+
+    /* A saved image loads back the same */
+    let image = snapshot::Image::from_window(&*canvas);
+    image.save(e, "/tmp/rust-efl-snapshot-copy.png").unwrap();
+    let loaded = snapshot::Image::load(e, "/tmp/rust-efl-snapshot-copy.png").unwrap();
+    let (mismatches, _) = snapshot::compare(&loaded, &image, 0);
+    assert_eq!(mismatches, 0);
+}
+
+fn main() {
+    ecore::evas_init();
+    run();
+    ecore::evas_shutdown();
+}
@@ -0,0 +1,89 @@
+/*
+ * Test clipping, layers and stacking of Evas objects.
+ *
+ * Three overlapping rectangles are restacked and clipped on a
+ * buffer canvas, checking which one ends up on top.
+ *
+ */
+
+extern crate efl;
+
+use efl::ecore;
+use efl::evas;
+
+
+fn run() {
+    let canvas = ecore::EcoreEvasWindow::buffer_new(200, 200);
+    let e = canvas.evas_get();
+
+    let red = evas::object_rectangle_add(e);
+    evas::object_color_set(red, 255, 0, 0, 255);
+    evas::object_move(red, (0, 0));
+    evas::object_resize(red, 100, 100);
+    evas::object_show(red);
+
+    let green = evas::object_rectangle_add(e);
+    evas::object_color_set(green, 0, 255, 0, 255);
+    evas::object_move(green, (50, 50));
+    evas::object_resize(green, 100, 100);
+    evas::object_show(green);
+
+    let blue = evas::object_rectangle_add(e);
+    evas::object_color_set(blue, 0, 0, 255, 255);
+    evas::object_move(blue, (25, 25));
+    evas::object_resize(blue, 50, 50);
+    evas::object_show(blue);
+
+    let is = |a: Option<&evas::EvasObject>, b: &evas::EvasObject| {
+        a.map_or(false, |a| a as *evas::EvasObject == b as *evas::EvasObject)
+    };
+
+    /* Last added is on top */
+    assert!(is(evas::object_top_at_xy_get(e, (60, 60), false, false), blue));
+    evas::object_lower(blue);
+    assert!(is(evas::object_top_at_xy_get(e, (60, 60), false, false), green));
+    evas::object_stack_above(red, green);
+    assert!(is(evas::object_top_at_xy_get(e, (60, 60), false, false), red));
+    assert!(is(evas::object_above_get(green), red));
+
+    /* Higher layers win over stacking */
+    evas::object_layer_set(blue, 1);
+    assert_eq!(evas::object_layer_get(blue), 1);
+    assert!(is(evas::object_top_get(e), blue));
+
+    /* Pass events through the top object */
+    evas::object_pass_events_set(blue, true);
+    assert!(evas::object_pass_events_get(blue));
+    assert!(is(evas::object_top_at_xy_get(e, (60, 60), false, false), red));
+    assert!(is(evas::object_top_at_xy_get(e, (60, 60), true, false), blue));
+    evas::object_pass_events_set(blue, false);
+    evas::object_repeat_events_set(blue, true);
+    assert!(evas::object_repeat_events_get(blue));
+
+    for obj in evas::objects_at_xy_get(e, (60, 60), true, false) {
+        println!("Object at (60, 60): {}", obj as *evas::EvasObject);
+    }
+    assert_eq!(evas::objects_at_xy_get(e, (60, 60), true, false).count(), 3);
+
+    /* Clip the red and green squares to the area of the blue one */
+    let clip = evas::object_rectangle_add(e);
+    evas::object_move(clip, (40, 40));
+    evas::object_resize(clip, 80, 80);
+    evas::object_show(clip);
+    evas::object_clip_set(red, clip);
+    evas::object_clip_set(green, clip);
+    assert!(is(evas::object_clip_get(red), clip));
+    assert_eq!(evas::object_clipees_get(clip).count(), 2);
+    evas::object_clip_unset(green);
+    assert!(evas::object_clip_get(green).is_none());
+    assert_eq!(evas::object_clipees_get(clip).count(), 1);
+
+    assert!(evas::object_bottom_get(e).is_some());
+    canvas.manual_render();
+}
+
+fn main() {
+    ecore::evas_init();
+    run();
+    ecore::evas_shutdown();
+}
@@ -0,0 +1,81 @@
+/*
+ * Test Evas text and textblock objects.
+ *
+ * Show a title with an outline and shadow, and a formatted
+ * paragraph edited through textblock cursors.
+ *
+ */
+
+extern crate efl;
+
+use efl::ecore;
+use efl::evas;
+use efl::evas::text;
+
+static WIDTH: int = 320;
+static HEIGHT: int = 240;
+
+
+fn run() {
+    let window = ecore::EcoreEvasWindow::new(None, 10, 10, WIDTH, HEIGHT, "");
+    window.callback_delete_request_set(box |&mut: _w: &ecore::EcoreEvasWindow| {
+        ecore::main_loop_quit()
+    });
+    let e = window.evas_get();
+
+    let bg = evas::object_rectangle_add(e);
+    evas::object_color_set(bg, 255, 255, 255, 255);
+    evas::object_resize(bg, WIDTH, HEIGHT);
+    evas::object_show(bg);
+
+    /* Single line text */
+    let title = text::object_text_add(e);
+    text::text_font_set(title, "Sans", 24);
+    text::text_text_set(title, "Rust EFL");
+    text::text_style_set(title, text::EVAS_TEXT_STYLE_OUTLINE_SHADOW |
+                                text::EVAS_TEXT_STYLE_SHADOW_DIRECTION_BOTTOM);
+    text::text_outline_color_set(title, (0, 0, 128, 255));
+    text::text_shadow_color_set(title, (0, 0, 0, 96));
+    evas::object_color_set(title, 255, 255, 255, 255);
+    evas::object_move(title, (10, 10));
+    evas::object_show(title);
+    println!("Font: {}, advance: {}, inset: {}, ascent: {}",
+             text::text_font_get(title), text::text_horiz_advance_get(title),
+             text::text_inset_get(title), text::text_ascent_get(title));
+    println!("Second character at: {}", text::text_char_pos_get(title, 1));
+
+    /* Formatted text */
+    let style = text::TextblockStyle::new(
+        "DEFAULT='font=Sans font_size=12 color=#000 wrap=word' em='+ font_style=Italic'");
+    let tb = text::object_textblock_add(e);
+    text::textblock_style_set(tb, &style);
+    text::textblock_text_markup_set(tb, "Hello <em>textblock</em>!<br>Second line");
+    evas::object_move(tb, (10, 60));
+    evas::object_resize(tb, WIDTH - 20, HEIGHT - 70);
+    evas::object_show(tb);
+
+    let cur = text::TextblockCursor::new(tb);
+    cur.paragraph_last();
+    cur.format_append("br");
+    cur.text_append("Added by a cursor");
+    cur.paragraph_first();
+    cur.word_end();
+    println!("End of first word at {}: {}", cur.pos_get(),
+             cur.geometry_get(text::EvasTextblockCursorBefore));
+
+    let main_cur = text::textblock_cursor_get(tb);
+    main_cur.paragraph_first();
+    println!("Cursors: {}", cur.compare(&main_cur));
+    println!("Markup: {}", text::textblock_text_markup_get(tb));
+    println!("Formatted size: {}, native size: {}",
+             text::textblock_size_formatted_get(tb), text::textblock_size_native_get(tb));
+
+    window.show();
+    ecore::main_loop_begin();
+}
+
+fn main() {
+    ecore::evas_init();
+    run();
+    ecore::evas_shutdown();
+}
@@ -0,0 +1,55 @@
+/*
+ * Test ecore worker threads and main loop thread-safe calls.
+ *
+ * Sum numbers on the worker pool while reporting the progress back
+ * to the main loop, which stays responsive with a ticking timer.
+ *
+ */
+
+extern crate efl;
+
+use efl::ecore;
+use efl::ecore::thread;
+
+static TOTAL: u64 = 50000000;
+
+
+fn main() {
+    ecore::init();
+
+    let _tick = ecore::timer_add_closure(0.5, box |&mut:| {
+        println!("Main loop still alive");
+        true
+    });
+
+    let job = thread::feedback_run(proc(worker: &thread::Worker<u64>) {
+        let mut sum: u64 = 0;
+        let mut i: u64 = 0;
+        while i < TOTAL {
+            if worker.is_cancelled() { break }
+            sum += i;
+            i += 1;
+            if i % (TOTAL / 10) == 0 { worker.feedback(i * 100 / TOTAL); }
+        }
+        // Also ask the main loop directly for its current time.
+        let now = ecore::main_loop_thread_safe_call_sync(proc() { ecore::time_get() });
+        println!("Worker finished at main loop time {}", now);
+        sum
+    }, box |&mut: percent: u64| {
+        println!("Progress: {}%", percent);
+    }, proc(sum: u64) {
+        println!("Sum: {}", sum);
+        ecore::main_loop_quit();
+    }, proc() {
+        println!("Job cancelled");
+        ecore::main_loop_quit();
+    }, false);
+
+    println!("Running jobs: {}, pending: {}, max: {}",
+             thread::active_get(), thread::pending_get(), thread::max_get());
+
+    ecore::main_loop_begin();
+
+    println!("Job finished: {}", job.is_finished());
+    ecore::shutdown();
+}
@@ -0,0 +1,86 @@
+/*
+ * Test the owned Ecore_Evas window.
+ *
+ * Open a window, change some of its properties and print the
+ * events it gets. Close the window to quit.
+ *
+ */
+
+extern crate efl;
+
+use efl::ecore;
+use efl::evas;
+
+static WIDTH: int = 320;
+static HEIGHT: int = 240;
+
+
+fn run() {
+    let window = ecore::EcoreEvasWindow::new(None, 10, 10, WIDTH, HEIGHT, "");
+    if !window.is_valid() {
+        println!("Could not create the window");
+        fail!()
+    }
+
+    window.title_set("Rust EFL window");
+    window.borderless_set(false);
+    println!("Title: {}", window.title_get());
+
+    let e = window.evas_get();
+    let bg = evas::object_rectangle_add(e);
+    evas::object_color_set(bg, 40, 80, 120, 255);
+    evas::object_resize(bg, WIDTH, HEIGHT);
+    evas::object_show(bg);
+
+    window.callback_delete_request_set(box |&mut: _w: &ecore::EcoreEvasWindow| {
+        println!("Delete request, quitting");
+        ecore::main_loop_quit();
+    });
+    window.callback_show_set(box |&mut: w: &ecore::EcoreEvasWindow| {
+        println!("Shown: {}", w.visibility_get());
+    });
+    window.callback_hide_set(box |&mut: _w: &ecore::EcoreEvasWindow| println!("Hidden"));
+    window.callback_move_set(box |&mut: w: &ecore::EcoreEvasWindow| {
+        println!("Moved: {}", w.geometry_get());
+    });
+    let r: *evas::EvasObject = &*bg;
+    window.callback_resize_set(box move |&mut: w: &ecore::EcoreEvasWindow| {
+        let (_, _, width, height) = w.geometry_get();
+        evas::object_resize(unsafe { &*r }, width, height);
+        println!("Resized: {}x{}", width, height);
+    });
+    window.callback_focus_in_set(box |&mut: _w: &ecore::EcoreEvasWindow| println!("Focus in"));
+    window.callback_focus_out_set(box |&mut: _w: &ecore::EcoreEvasWindow| println!("Focus out"));
+    let mut frames: uint = 0;
+    window.callback_post_render_set(box move |&mut: _w: &ecore::EcoreEvasWindow| {
+        frames += 1;
+        if frames == 1 { println!("First frame rendered") }
+    });
+
+    window.show();
+
+    /* Play with the window properties */
+    let w: *ecore::EcoreEvasWindow = &*window;
+    let mut step: uint = 0;
+    let _timer = ecore::timer_add_closure(1.0, box move |&mut:| {
+        let w = unsafe { &*w };
+        step += 1;
+        match step {
+            1 => w.move_resize(50, 50, WIDTH * 2, HEIGHT * 2),
+            2 => w.rotation_set(90),
+            3 => { w.rotation_set(0); w.maximized_set(true) },
+            4 => println!("Maximized: {}", w.maximized_get()),
+            _ => { w.maximized_set(false); return false }
+        }
+        true
+    });
+
+    ecore::main_loop_begin();
+}
+
+fn main() {
+    ecore::evas_init();
+    /* The window is freed when 'run' returns */
+    run();
+    ecore::evas_shutdown();
+}